@@ -0,0 +1,83 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Member};
+
+/// Derives `range_utils::BasicNum` for a struct with a single field, e.g. `struct Tick(u64);`
+///
+/// All methods and constants are forwarded to the field, which has to implement `BasicNum` and `PartialEq` itself. This includes `checked_*`/`saturating_*`, which (as required by `BasicNum`) can only be called if the struct implements `PartialEq`, so it should be derived as well.
+#[proc_macro_derive(BasicNum)]
+pub fn derive_basic_num(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => return error(&input, "`BasicNum` can only be derived for structs"),
+    };
+    let field = match fields.iter().collect::<Vec<_>>()[..] {
+        [field] => field,
+        _ => {
+            return error(
+                &input,
+                "`BasicNum` can only be derived for structs with a single field",
+            )
+        }
+    };
+    let (inner, name) = (&field.ty, &input.ident);
+    let member = field
+        .ident
+        .clone()
+        .map_or(Member::Unnamed(0.into()), Member::Named);
+    // `Self { 0: x }` works for tuple structs as well
+    let wrap = |x: TokenStream2| quote!(Self { #member: #x });
+    let mut generics = input.generics.clone();
+    generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote!(#inner: ::range_utils::BasicNum + ::core::cmp::PartialEq));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let min = wrap(quote!(<#inner as ::range_utils::BasicNum>::MIN_VALUE));
+    let max = wrap(quote!(<#inner as ::range_utils::BasicNum>::MAX_VALUE));
+    let dec = wrap(quote!(::range_utils::BasicNum::dec(&self.#member)));
+    let inc = wrap(quote!(::range_utils::BasicNum::inc(&self.#member)));
+    let saturating_dec = wrap(quote!(::range_utils::BasicNum::saturating_dec(&self.#member)));
+    let saturating_inc = wrap(quote!(::range_utils::BasicNum::saturating_inc(&self.#member)));
+    let wrap_some = wrap(quote!(x));
+    let from_offset = wrap(quote!(<#inner as ::range_utils::BasicNum>::from_offset(offset)));
+    quote! {
+        impl #impl_generics ::range_utils::BasicNum for #name #ty_generics #where_clause {
+            const MIN_VALUE: Self = #min;
+            const MAX_VALUE: Self = #max;
+            fn dec(&self) -> Self {
+                #dec
+            }
+            fn inc(&self) -> Self {
+                #inc
+            }
+            fn checked_dec(&self) -> ::core::option::Option<Self> {
+                ::range_utils::BasicNum::checked_dec(&self.#member).map(|x| #wrap_some)
+            }
+            fn checked_inc(&self) -> ::core::option::Option<Self> {
+                ::range_utils::BasicNum::checked_inc(&self.#member).map(|x| #wrap_some)
+            }
+            fn saturating_dec(&self) -> Self {
+                #saturating_dec
+            }
+            fn saturating_inc(&self) -> Self {
+                #saturating_inc
+            }
+            fn offset_from_min(&self) -> u128 {
+                ::range_utils::BasicNum::offset_from_min(&self.#member)
+            }
+            fn from_offset(offset: u128) -> Self {
+                #from_offset
+            }
+        }
+    }
+    .into()
+}
+
+fn error(input: &DeriveInput, message: &str) -> TokenStream {
+    Error::new_spanned(&input.ident, message)
+        .to_compile_error()
+        .into()
+}
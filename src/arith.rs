@@ -0,0 +1,76 @@
+//! Overflow checks for generic arithmetic on `BasicInt` types
+
+use core::ops::{Add, Div, Mul, Sub};
+
+use crate::BasicInt;
+
+/// `a + b`, `None` on overflow
+pub(crate) fn checked_add<T>(a: T, b: T) -> Option<T>
+where
+    T: Ord + Clone + BasicInt + Add<Output = T> + Sub<Output = T>,
+{
+    // `MAX - b` (`MIN - b`) can't overflow if `b` isn't negative (is negative)
+    let fits = if b >= T::ZERO {
+        a <= T::MAX_VALUE - b.clone()
+    } else {
+        a >= T::MIN_VALUE - b.clone()
+    };
+    fits.then(|| a + b)
+}
+/// `a - b`, `None` on overflow
+pub(crate) fn checked_sub<T>(a: T, b: T) -> Option<T>
+where
+    T: Ord + Clone + BasicInt + Add<Output = T> + Sub<Output = T>,
+{
+    // `MIN + b` (`MAX + b`) can't overflow if `b` isn't negative (is negative)
+    let fits = if b >= T::ZERO {
+        a >= T::MIN_VALUE + b.clone()
+    } else {
+        a <= T::MAX_VALUE + b.clone()
+    };
+    fits.then(|| a - b)
+}
+/// `a + b`, staying at `MIN_VALUE`/`MAX_VALUE` instead of overflowing
+pub(crate) fn saturating_add<T>(a: T, b: T) -> T
+where
+    T: Ord + Clone + BasicInt + Add<Output = T> + Sub<Output = T>,
+{
+    let positive = b >= T::ZERO;
+    checked_add(a, b).unwrap_or(if positive { T::MAX_VALUE } else { T::MIN_VALUE })
+}
+/// `a - b`, staying at `MIN_VALUE`/`MAX_VALUE` instead of overflowing
+pub(crate) fn saturating_sub<T>(a: T, b: T) -> T
+where
+    T: Ord + Clone + BasicInt + Add<Output = T> + Sub<Output = T>,
+{
+    let positive = b >= T::ZERO;
+    checked_sub(a, b).unwrap_or(if positive { T::MIN_VALUE } else { T::MAX_VALUE })
+}
+/// `a * b`, `None` on overflow
+pub(crate) fn checked_mul<T>(a: T, b: T) -> Option<T>
+where
+    T: Ord + Clone + BasicInt + Mul<Output = T> + Div<Output = T>,
+{
+    let zero = T::ZERO;
+    if a == zero || b == zero {
+        return Some(zero);
+    }
+    if b < zero && a > zero {
+        return checked_mul(b, a);
+    }
+    // `MAX / b` and `MIN / b` can't overflow if `b` is positive, `MAX / b` neither if `b` is negative (division truncates towards zero)
+    let fits = if b > zero {
+        a <= T::MAX_VALUE / b.clone() && a >= T::MIN_VALUE / b.clone()
+    } else {
+        a >= T::MAX_VALUE / b.clone()
+    };
+    fits.then(|| a * b)
+}
+/// `a * b`, staying at `MIN_VALUE`/`MAX_VALUE` instead of overflowing
+pub(crate) fn saturating_mul<T>(a: T, b: T) -> T
+where
+    T: Ord + Clone + BasicInt + Mul<Output = T> + Div<Output = T>,
+{
+    let positive = (a >= T::ZERO) == (b >= T::ZERO);
+    checked_mul(a, b).unwrap_or(if positive { T::MAX_VALUE } else { T::MIN_VALUE })
+}
@@ -0,0 +1,80 @@
+use core::{
+    iter::FusedIterator,
+    ops::{Add, RangeInclusive, Sub},
+};
+
+use crate::{arith, BasicInt, BasicNum};
+
+/// Iterator over consecutive sub-ranges of a fixed size, see `RangeUtil::chunks`
+#[derive(Debug, Clone)]
+pub struct Chunks<T> {
+    /// The elements not yielded yet, `None` if exhausted
+    remaining: Option<RangeInclusive<T>>,
+    size: T,
+}
+impl<T: Ord + Clone + BasicInt + Add<Output = T> + Sub<Output = T>> Chunks<T> {
+    pub(crate) fn new(start: T, end: T, size: T) -> Self {
+        assert!(size > T::ZERO, "chunk size must be positive");
+        Self {
+            remaining: Some(start..=end).filter(|r| !r.is_empty()),
+            size,
+        }
+    }
+}
+impl<T: Ord + Clone + BasicNum + Add<Output = T> + Sub<Output = T>> Iterator for Chunks<T> {
+    type Item = RangeInclusive<T>;
+
+    fn next(&mut self) -> Option<RangeInclusive<T>> {
+        let (start, end) = self.remaining.take()?.into_inner();
+        let step = self.size.dec();
+        // `MAX_VALUE - step` can't overflow since `step` isn't negative
+        if start <= T::MAX_VALUE - step.clone() {
+            let chunk_end = start.clone() + step;
+            if chunk_end < end {
+                // `chunk_end < end`, so `inc` can't overflow
+                self.remaining = Some(chunk_end.inc()..=end);
+                return Some(start..=chunk_end);
+            }
+        }
+        Some(start..=end)
+    }
+}
+impl<T: Ord + Clone + BasicNum + Add<Output = T> + Sub<Output = T>> FusedIterator for Chunks<T> {}
+
+/// Iterator over overlapping sub-ranges of a fixed size advancing by one element, see `RangeUtil::windows`
+#[derive(Debug, Clone)]
+pub struct Windows<T> {
+    /// The start of the next window, `None` if exhausted
+    next: Option<T>,
+    /// The start of the window ending at the end of the range
+    last: T,
+    /// `size - 1`, i.e. the distance between the start and the end of a window
+    step: T,
+}
+impl<T: Ord + Clone + BasicInt + Add<Output = T> + Sub<Output = T>> Windows<T> {
+    pub(crate) fn new(start: T, end: T, size: T) -> Self {
+        assert!(size > T::ZERO, "window size must be positive");
+        let step = size.dec();
+        // overflowing means that the range has less than `size` elements
+        let last = arith::checked_sub(end, step.clone()).filter(|last| &start <= last);
+        Self {
+            next: last.is_some().then_some(start),
+            last: last.unwrap_or(T::MIN_VALUE),
+            step,
+        }
+    }
+}
+impl<T: Ord + Clone + BasicNum + Add<Output = T> + Sub<Output = T>> Iterator for Windows<T> {
+    type Item = RangeInclusive<T>;
+
+    fn next(&mut self) -> Option<RangeInclusive<T>> {
+        let start = self.next.take()?;
+        if start < self.last {
+            self.next = Some(start.inc());
+        }
+        // `start <= last`, so this can't overflow
+        let end = start.clone() + self.step.clone();
+        Some(start..=end)
+    }
+}
+impl<T: Ord + Clone + BasicNum + Add<Output = T> + Sub<Output = T>> FusedIterator for Windows<T> {}
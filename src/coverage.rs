@@ -0,0 +1,197 @@
+//! Counting how many ranges cover elements
+
+use core::ops::RangeInclusive;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::coalesce;
+use crate::{BasicNum, RangeUtil};
+
+/// The number of `ranges` including `point`, e.g. `[0..=5, 3..=8, 7..=9]` at `4` is `2`
+pub fn count_overlapping<T: Ord + Clone + BasicNum>(
+    ranges: &[RangeInclusive<T>],
+    point: &T,
+) -> usize {
+    ranges.iter().filter(|r| r.includes(point)).count()
+}
+
+/// The maximum number of `ranges` including a common element, e.g. `[0..=5, 3..=8, 7..=9]` is `2`
+///
+/// This is a sweep over the sorted starts and ends, so it runs in `O(n log n)`. Ranges sharing only an end and a start (e.g. `0..=3` and `3..=5`) overlap, adjacent ones don't. `0` if there are no (non-empty) ranges.
+#[cfg(feature = "alloc")]
+pub fn max_coverage<T: Ord + Clone + BasicNum>(ranges: &[RangeInclusive<T>]) -> usize {
+    let ranges = ranges.iter().filter(|r| !r.is_empty());
+    let mut starts = ranges.clone().map(|r| r.start()).collect::<Vec<_>>();
+    let mut ends = ranges.map(|r| r.end()).collect::<Vec<_>>();
+    starts.sort_unstable();
+    ends.sort_unstable();
+    let (mut ended, mut max) = (0, 0);
+    for (started, start) in starts.into_iter().enumerate() {
+        // ranges ending before `start` don't cover it
+        while ends[ended] < start {
+            ended += 1;
+        }
+        max = max.max(started + 1 - ended);
+    }
+    max
+}
+
+/// The number of distinct elements covered by `ranges`, e.g. `[0..=4, 2..=6]` is `7` (not `5 + 5`)
+///
+/// The ranges are `coalesce`d first, the count saturates at `u128::MAX` (like `RangeUtil::cardinality`).
+#[cfg(feature = "alloc")]
+pub fn covered_count<T: Ord + Clone + BasicNum>(ranges: &[RangeInclusive<T>]) -> u128 {
+    coalesce(ranges)
+        .iter()
+        .fold(0, |count, r| count.saturating_add(r.cardinality()))
+}
+
+/// Splits weighted ranges into disjoint ranges carrying the sum of the weights covering them, e.g. `[(0..=4, 1), (2..=6, 2)]` is `[(0..=1, 1), (2..=4, 3), (5..=6, 2)]`
+///
+/// This is a sweep over the starts and (exclusive) ends. The result is sorted by start, adjacent pieces with the same sum are merged and elements covered by no range are left out (but covered ones with a sum of `0` are not). The sums are computed as `i128`, so they are exact while sweeping, and saturate at `i64::MIN`/`i64::MAX` in the result.
+#[cfg(feature = "alloc")]
+pub fn flatten_weighted<T: Ord + Clone + BasicNum>(
+    items: &[(RangeInclusive<T>, i64)],
+) -> Vec<(RangeInclusive<T>, i64)> {
+    // `(element, weight change, coverage change)`, there is no end event for ranges ending at `MAX_VALUE`
+    let mut events = Vec::new();
+    for (range, weight) in items.iter().filter(|(r, _)| !r.is_empty()) {
+        let weight = i128::from(*weight);
+        events.push((range.start().clone(), weight, 1));
+        if let Some(after) = range.end().checked_inc() {
+            events.push((after, -weight, -1));
+        }
+    }
+    events.sort_unstable_by(|(a, ..), (b, ..)| a.cmp(b));
+    let mut flattened: Vec<(RangeInclusive<T>, i64)> = Vec::new();
+    let (mut weight, mut coverage, mut i) = (0, 0, 0);
+    while i < events.len() {
+        let start = events[i].0.clone();
+        while events.get(i).is_some_and(|(x, ..)| x == &start) {
+            weight += events[i].1;
+            coverage += events[i].2;
+            i += 1;
+        }
+        if coverage == 0 {
+            continue;
+        }
+        // the next event is after `start`, so `dec` can't overflow
+        let end = events.get(i).map_or(T::MAX_VALUE, |(next, ..)| next.dec());
+        let sum = weight.clamp(i64::MIN.into(), i64::MAX.into()) as i64;
+        match flattened.last_mut() {
+            Some((last, last_weight))
+                if *last_weight == sum && last.is_adjacent(&(start.clone()..=end.clone())) =>
+            {
+                *last = last.start().clone()..=end;
+            }
+            _ => flattened.push((start..=end, sum)),
+        }
+    }
+    flattened
+}
+
+#[cfg(test)]
+mod tests {
+    use super::count_overlapping;
+    #[cfg(feature = "alloc")]
+    use super::{covered_count, flatten_weighted, max_coverage};
+
+    #[test]
+    fn test_count_overlapping() {
+        let ranges = [0..=5, 3..=8, 7..=9, 4..=4];
+        assert_eq!(count_overlapping(&ranges, &4), 3);
+        assert_eq!(count_overlapping(&ranges, &3), 2);
+        assert_eq!(count_overlapping(&ranges, &6), 1);
+        assert_eq!(count_overlapping(&ranges, &7), 2);
+        assert_eq!(count_overlapping(&ranges, &10), 0);
+        assert_eq!(count_overlapping(&[], &0), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_max_coverage() {
+        // staggered
+        assert_eq!(max_coverage(&[0..=5, 3..=8, 7..=9]), 2);
+        assert_eq!(max_coverage(&[0..=3, 3..=5]), 2);
+        assert_eq!(max_coverage(&[0..=3, 4..=5]), 1);
+        // nested
+        assert_eq!(max_coverage(&[0..=10, 2..=8, 4..=6, 5..=5]), 4);
+        assert_eq!(max_coverage(&[0..=10, 1..=2, 3..=4, 5..=6]), 2);
+        assert_eq!(max_coverage(&[5..=5, 5..=5, 5..=5]), 3);
+        assert_eq!(max_coverage(&[7..=9, 0..=10, 8..=8]), 3);
+        assert_eq!(max_coverage(&[3..=2, 0..=1]), 1);
+        assert_eq!(max_coverage::<i32>(&[]), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_covered_count() {
+        // overlapping
+        assert_eq!(covered_count(&[0..=4, 2..=6]), 7);
+        assert_eq!(covered_count(&[0..=10, 2..=3, 5..=5]), 11);
+        // disjoint and adjacent
+        assert_eq!(covered_count(&[0..=4, 10..=12]), 8);
+        assert_eq!(covered_count(&[0..=4, 5..=6]), 7);
+        assert_eq!(covered_count(&[3..=2, 0..=0]), 1);
+        assert_eq!(covered_count::<i32>(&[]), 0);
+        assert_eq!(covered_count(&[i64::MIN..=i64::MAX, 0..=0]), 1 << 64);
+        assert_eq!(covered_count(&[0..=u128::MAX]), u128::MAX);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_flatten_weighted() {
+        assert_eq!(
+            flatten_weighted(&[(0..=4, 1), (2..=6, 2)]),
+            [(0..=1, 1), (2..=4, 3), (5..=6, 2)]
+        );
+        assert_eq!(
+            flatten_weighted(&[(2..=6, 2), (0..=4, 1)]),
+            [(0..=1, 1), (2..=4, 3), (5..=6, 2)]
+        );
+        // sharing an end / a start
+        assert_eq!(
+            flatten_weighted(&[(0..=4, 1), (4..=6, 2)]),
+            [(0..=3, 1), (4..=4, 3), (5..=6, 2)]
+        );
+        assert_eq!(
+            flatten_weighted(&[(0..=4, 1), (0..=6, 2)]),
+            [(0..=4, 3), (5..=6, 2)]
+        );
+        // nested
+        assert_eq!(
+            flatten_weighted(&[(0..=10, 1), (3..=5, 5)]),
+            [(0..=2, 1), (3..=5, 6), (6..=10, 1)]
+        );
+        // gaps are left out, adjacent pieces with equal sums are merged
+        assert_eq!(
+            flatten_weighted(&[(0..=2, 1), (5..=6, 1)]),
+            [(0..=2, 1), (5..=6, 1)]
+        );
+        assert_eq!(flatten_weighted(&[(0..=2, 1), (3..=6, 1)]), [(0..=6, 1)]);
+        assert_eq!(
+            flatten_weighted(&[(0..=4, 1), (2..=4, -1)]),
+            [(0..=1, 1), (2..=4, 0)]
+        );
+        assert_eq!(flatten_weighted(&[(0..=4, 1), (3..=2, 7)]), [(0..=4, 1)]);
+        assert_eq!(
+            flatten_weighted(&[(250u8..=255, 1), (0..=255, 1)]),
+            [(0..=249, 1), (250..=255, 2)]
+        );
+        assert!(flatten_weighted::<i32>(&[]).is_empty());
+        // the sums are exact while sweeping and saturate in the result
+        assert_eq!(
+            flatten_weighted(&[(0..=9, i64::MAX), (0..=4, i64::MAX), (3..=9, i64::MIN)]),
+            [(0..=2, i64::MAX), (3..=4, i64::MAX - 1), (5..=9, -1)]
+        );
+        assert_eq!(
+            flatten_weighted(&[(0..=4, i64::MIN), (2..=6, i64::MIN), (4..=9, 1)]),
+            [(0..=4, i64::MIN), (5..=6, i64::MIN + 1), (7..=9, 1)]
+        );
+    }
+}
@@ -0,0 +1,34 @@
+use core::fmt::{self, Display};
+
+/// Error returned by `RangeUtil::checked_intersection`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IntersectError {
+    /// The ranges have a gap between them, e.g. `0..=3` and `5..=9`; also if either range is empty
+    Disjoint,
+    /// The ranges touch without a common element, e.g. `0..=3` and `4..=9`
+    Adjacent,
+}
+impl Display for IntersectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Disjoint => "ranges are disjoint",
+            Self::Adjacent => "ranges are adjacent",
+        })
+    }
+}
+impl core::error::Error for IntersectError {}
+
+/// Error returned by `try_from_bounds`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RangeError {
+    /// The bounds don't contain any element, e.g. `(Excluded(3), Included(3))`
+    Empty,
+}
+impl Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Empty => "range is empty",
+        })
+    }
+}
+impl core::error::Error for RangeError {}
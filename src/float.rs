@@ -0,0 +1,151 @@
+use core::{
+    cmp::Ordering,
+    ops::{Bound, RangeBounds, RangeInclusive, Sub},
+};
+
+/// Basic operations (next representable value) for floats
+pub trait BasicFloat: PartialOrd + Copy + Sub<Output = Self> {
+    const MIN_VALUE: Self;
+    const MAX_VALUE: Self;
+    fn dec(&self) -> Self;
+    fn inc(&self) -> Self;
+}
+macro_rules! impl_primitive_basic_float {
+    ($($t:ty),*) => {
+        $(
+            impl BasicFloat for $t {
+                const MIN_VALUE: Self = Self::NEG_INFINITY;
+                const MAX_VALUE: Self = Self::INFINITY;
+                fn dec(&self) -> Self {
+                    self.next_down()
+                }
+                fn inc(&self) -> Self {
+                    self.next_up()
+                }
+            }
+        )*
+    };
+}
+impl_primitive_basic_float!(f32, f64);
+
+/// `RangeUtil` for float ranges, unbounded sides are infinite and excluded bounds are the next representable value
+///
+/// Ranges containing `NaN` are empty.
+pub trait FloatRangeUtil<T: BasicFloat>: Sized + Clone {
+    /// Start bound inclusive
+    fn starts_at(&self) -> T;
+    /// End bound inclusive
+    fn ends_at(&self) -> T;
+
+    /// The continuous length of the range, i.e. `ends_at() - starts_at()`, e.g. `(0.0..=1.5).len() == Some(1.5)`
+    ///
+    /// Returns `None` if the range is empty.
+    fn len(&self) -> Option<T> {
+        (!self.is_empty()).then(|| self.ends_at() - self.starts_at())
+    }
+    /// Whether the range contains no values
+    fn is_empty(&self) -> bool {
+        // `NaN` is incomparable, so such ranges are empty
+        !matches!(
+            self.starts_at().partial_cmp(&self.ends_at()),
+            Some(Ordering::Less | Ordering::Equal)
+        )
+    }
+    /// Same as `RangeUtil::includes`
+    fn includes(&self, x: &T) -> bool {
+        &self.starts_at() <= x && x <= &self.ends_at()
+    }
+    /// Same as `RangeUtil::intersects`
+    fn intersects(&self, other: &impl FloatRangeUtil<T>) -> bool {
+        !self.is_empty()
+            && !other.is_empty()
+            && self.ends_at() >= other.starts_at()
+            && self.starts_at() <= other.ends_at()
+    }
+    /// Same as `RangeUtil::intersection`
+    fn intersection(&self, other: &impl FloatRangeUtil<T>) -> Option<RangeInclusive<T>> {
+        let max = |a: T, b: T| if a < b { b } else { a };
+        let min = |a: T, b: T| if a > b { b } else { a };
+        self.intersects(other).then(|| {
+            max(self.starts_at(), other.starts_at())..=min(self.ends_at(), other.ends_at())
+        })
+    }
+    /// Same as `RangeUtil::clamp_value`, `x` is returned unchanged for empty ranges
+    fn clamp_value(&self, x: T) -> T {
+        if self.is_empty() {
+            x
+        } else if x < self.starts_at() {
+            self.starts_at()
+        } else if x > self.ends_at() {
+            self.ends_at()
+        } else {
+            x
+        }
+    }
+}
+impl<T: BasicFloat, R: RangeBounds<T> + Clone> FloatRangeUtil<T> for R {
+    fn starts_at(&self) -> T {
+        match self.start_bound() {
+            Bound::Excluded(x) => x.inc(),
+            Bound::Included(x) => *x,
+            Bound::Unbounded => T::MIN_VALUE,
+        }
+    }
+    fn ends_at(&self) -> T {
+        match self.end_bound() {
+            Bound::Excluded(x) => x.dec(),
+            Bound::Included(x) => *x,
+            Bound::Unbounded => T::MAX_VALUE,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::RangeFull;
+
+    use super::{BasicFloat, FloatRangeUtil};
+
+    #[test]
+    fn test_bounds() {
+        assert_eq!((0.0..1.0).starts_at(), 0.0);
+        assert_eq!((0.0..1.0).ends_at(), 1.0.dec());
+        assert_eq!((..=1.0).starts_at(), f64::NEG_INFINITY);
+        assert_eq!(FloatRangeUtil::<f32>::ends_at(&RangeFull), f32::INFINITY);
+    }
+
+    #[test]
+    fn test_len() {
+        assert_eq!(FloatRangeUtil::len(&(0.0..=1.5)), Some(1.5));
+        assert_eq!(FloatRangeUtil::len(&(1.0..=1.0)), Some(0.0));
+        assert_eq!(FloatRangeUtil::len(&(1.0..1.0)), None);
+        assert_eq!(FloatRangeUtil::len(&(0.0..)), Some(f64::INFINITY));
+        assert_eq!(FloatRangeUtil::len(&(0.0..=f64::NAN)), None);
+    }
+
+    #[test]
+    fn test_includes_intersects() {
+        assert!((0.0..=1.0).includes(&0.5));
+        assert!((0.0..=1.0).includes(&1.0));
+        assert!(!(0.0..1.0).includes(&1.0));
+        assert!(!(0.0..=1.0).includes(&f64::NAN));
+        assert!((0.0..=1.0).intersects(&(1.0..)));
+        assert!(!(0.0..1.0).intersects(&(1.0..)));
+        assert!(!(0.0..=1.0).intersects(&(0.5..0.5)));
+    }
+
+    #[test]
+    fn test_intersection() {
+        assert_eq!((0.0..=1.0).intersection(&(0.5..=2.0)), Some(0.5..=1.0));
+        assert_eq!((0.0f32..=1.0).intersection(&(..0.5)), Some(0.0..=0.5.dec()));
+        assert_eq!((0.0..1.0).intersection(&(1.0..)), None);
+    }
+
+    #[test]
+    fn test_clamp_value() {
+        assert_eq!((0.0..=1.0).clamp_value(-3.0), 0.0);
+        assert_eq!((0.0..=1.0).clamp_value(0.25), 0.25);
+        assert_eq!((0.0..=1.0).clamp_value(3.0), 1.0);
+        assert_eq!((1.0..=0.0).clamp_value(3.0), 3.0);
+    }
+}
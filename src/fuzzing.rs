@@ -0,0 +1,84 @@
+use core::ops::RangeInclusive;
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{BasicNum, RangeSet};
+
+/// A non-empty `RangeInclusive<T>` (i.e. `start <= end`) implementing `Arbitrary`
+///
+/// `RangeInclusive<T>` itself implements `Arbitrary`, but may be reversed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ArbitraryRange<T>(pub RangeInclusive<T>);
+impl<'a, T: Arbitrary<'a> + Ord> Arbitrary<'a> for ArbitraryRange<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let (a, b) = (T::arbitrary(u)?, T::arbitrary(u)?);
+        Ok(Self(if a <= b { a..=b } else { b..=a }))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <(T, T)>::size_hint(depth)
+    }
+}
+impl<'a, T: Arbitrary<'a> + Ord + Clone + BasicNum> Arbitrary<'a> for RangeSet<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.arbitrary_iter::<ArbitraryRange<T>>()?
+            .map(|r| r.map(|r| r.0))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec::Vec;
+
+    use arbitrary::{Arbitrary, Unstructured};
+
+    use super::ArbitraryRange;
+    use crate::{RangeSet, RangeUtil};
+
+    /// Deterministic pseudo random bytes (xorshift), so the property tests are reproducible
+    fn bytes(seed: u64, len: usize) -> Vec<u8> {
+        let mut x = seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1;
+        (0..len)
+            .map(|_| {
+                x ^= x << 13;
+                x ^= x >> 7;
+                x ^= x << 17;
+                x as u8
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_arbitrary_range_is_not_empty() {
+        for seed in 0..1000 {
+            let bytes = bytes(seed, 16);
+            let ArbitraryRange(r) =
+                ArbitraryRange::<i32>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+            assert!(!r.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_intersection_is_commutative() {
+        for seed in 0..1000 {
+            let bytes = bytes(seed, 16);
+            let mut u = Unstructured::new(&bytes);
+            let ArbitraryRange(a) = ArbitraryRange::<i8>::arbitrary(&mut u).unwrap();
+            let ArbitraryRange(b) = ArbitraryRange::<i8>::arbitrary(&mut u).unwrap();
+            assert_eq!(a.intersection(&b), b.intersection(&a));
+        }
+    }
+
+    #[test]
+    fn test_arbitrary_range_set_is_normalized() {
+        for seed in 0..1000 {
+            let bytes = bytes(seed, 64);
+            let set = RangeSet::<i8>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+            let ranges = set.iter().collect::<Vec<_>>();
+            for pair in ranges.windows(2) {
+                assert!(pair[0].end() < pair[1].start() && !pair[0].is_adjacent(pair[1]));
+            }
+        }
+    }
+}
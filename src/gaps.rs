@@ -0,0 +1,192 @@
+use core::{
+    iter::FusedIterator,
+    ops::{Add, RangeInclusive, Sub},
+    slice,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::coalesce;
+use crate::{BasicInt, BasicNum, RangeUtil};
+
+/// Iterator over the elements of a universe not covered by a sorted list of ranges, see `iter_gaps`
+#[derive(Debug, Clone)]
+pub struct Gaps<'a, T> {
+    ranges: slice::Iter<'a, RangeInclusive<T>>,
+    /// The first element not covered so far and the end of the universe, `None` if exhausted
+    remaining: Option<(T, T)>,
+}
+impl<T: Ord + Clone + BasicNum> Iterator for Gaps<'_, T> {
+    type Item = RangeInclusive<T>;
+
+    fn next(&mut self) -> Option<RangeInclusive<T>> {
+        loop {
+            let (start, end) = self.remaining.take()?;
+            let Some(range) = self.ranges.next() else {
+                return Some(start..=end);
+            };
+            if range.is_empty() || range.end() < &start {
+                self.remaining = Some((start, end));
+                continue;
+            }
+            // nothing is left if `range` ends at (or after) the end of the universe
+            if let Some(next) = range.end().checked_inc().filter(|next| next <= &end) {
+                self.remaining = Some((next, end.clone()));
+            }
+            if range.start() > &start {
+                // `range.start() > start`, so `dec` can't overflow
+                return Some(start..=range.start().dec().min(end));
+            }
+        }
+    }
+}
+impl<T: Ord + Clone + BasicNum> FusedIterator for Gaps<'_, T> {}
+
+/// Iterates over the gaps between `ranges` within `universe`, e.g. `[0..=2, 6..=8]` in `0..=10` yields `3..=5, 9..=10`
+///
+/// `ranges` have to be sorted by their start and disjoint, like the result of `coalesce` or `RangeSet::iter`; empty ranges are skipped. Leading and trailing gaps are included, so without any ranges the whole universe (if not empty) is yielded.
+pub fn iter_gaps<'a, T: Ord + Clone + BasicNum>(
+    ranges: &'a [RangeInclusive<T>],
+    universe: &impl RangeUtil<T>,
+) -> Gaps<'a, T> {
+    let (start, end) = universe.to_inclusive().into_inner();
+    Gaps {
+        ranges: ranges.iter(),
+        remaining: (start <= end).then_some((start, end)),
+    }
+}
+
+/// The first `n` elements of the first gap with at least `n` elements, e.g. `[0..=2, 10..=12]` in `0..=20` with `n == 4` is `3..=6`
+///
+/// This searches `iter_gaps(ranges, universe)`, so `ranges` have to be sorted and disjoint. `None` if no gap is large enough. Panics if `n` isn't positive.
+pub fn first_gap_of_len<T>(
+    ranges: &[RangeInclusive<T>],
+    universe: &impl RangeUtil<T>,
+    n: T,
+) -> Option<RangeInclusive<T>>
+where
+    T: Ord + Clone + BasicInt + Add<Output = T> + Sub<Output = T>,
+{
+    assert!(n > T::ZERO, "length must be positive");
+    let last = n.dec();
+    iter_gaps(ranges, universe).find_map(|gap| Some(gap.start().clone()..=gap.nth(last.clone())?))
+}
+
+/// Splits `universe` into the covered parts and the gaps, e.g. `[0..=2, 6..=8]` in `0..=10` is `[(0..=2, true), (3..=5, false), (6..=8, true), (9..=10, false)]`
+///
+/// `ranges` don't have to be sorted or disjoint, they are `coalesce`d and restricted to `universe` first. The segments are sorted by their start and alternate between covered (`true`) and gaps (`false`).
+#[cfg(feature = "alloc")]
+pub fn segment_cover<T: Ord + Clone + BasicNum>(
+    ranges: &[RangeInclusive<T>],
+    universe: &impl RangeUtil<T>,
+) -> Vec<(RangeInclusive<T>, bool)> {
+    let covered = coalesce(ranges)
+        .iter()
+        .filter_map(|r| r.intersection(universe))
+        .collect::<Vec<_>>();
+    let mut segments = iter_gaps(&covered, universe)
+        .map(|gap| (gap, false))
+        .collect::<Vec<_>>();
+    segments.extend(covered.into_iter().map(|r| (r, true)));
+    segments.sort_unstable_by(|(a, _), (b, _)| a.start().cmp(b.start()));
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec::Vec;
+
+    #[cfg(feature = "alloc")]
+    use super::segment_cover;
+    use super::{first_gap_of_len, iter_gaps};
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_iter_gaps() {
+        let gaps = |ranges: &[std::ops::RangeInclusive<i32>], universe| {
+            iter_gaps(ranges, &universe).collect::<Vec<_>>()
+        };
+        assert_eq!(gaps(&[0..=2, 6..=8], 0..=10), [3..=5, 9..=10]);
+        assert_eq!(gaps(&[2..=2, 6..=8], 0..=10), [0..=1, 3..=5, 9..=10]);
+        assert_eq!(gaps(&[0..=2, 6..=10], 0..=10), [3..=5]);
+        assert_eq!(gaps(&[0..=2, 3..=10], 0..=10), []);
+        assert_eq!(gaps(&[], 0..=10), [0..=10]);
+        assert_eq!(gaps(&[], 5..=4), []);
+        assert_eq!(gaps(&[-5..=-3, 0..=2], 0..=10), [3..=10]);
+        assert_eq!(gaps(&[-5..=1, 12..=20], 0..=10), [2..=10]);
+        assert_eq!(gaps(&[4..=6, 12..=20], 0..=10), [0..=3, 7..=10]);
+        assert_eq!(gaps(&[-5..=20, 30..=40], 0..=10), []);
+        assert_eq!(gaps(&[4..=3, 6..=8], 0..=10), [0..=5, 9..=10]);
+
+        let ranges = [0u8..=0, 255..=255];
+        assert_eq!(iter_gaps(&ranges, &(..)).collect::<Vec<_>>(), [1..=254]);
+        assert_eq!(iter_gaps(&[], &(250u8..)).collect::<Vec<_>>(), [250..=255]);
+        assert_eq!(
+            iter_gaps(&[10u8..=20], &(..)).collect::<Vec<_>>(),
+            [0..=9, 21..=255]
+        );
+        assert_eq!(iter_gaps(&[], &(..0u8)).next(), None);
+    }
+
+    #[test]
+    fn test_first_gap_of_len() {
+        let occupied = [0..=2, 10..=12];
+        assert_eq!(first_gap_of_len(&occupied, &(0..=20), 4), Some(3..=6));
+        assert_eq!(first_gap_of_len(&occupied, &(0..=20), 7), Some(3..=9));
+        assert_eq!(first_gap_of_len(&occupied, &(0..=20), 8), Some(13..=20));
+        assert_eq!(first_gap_of_len(&occupied, &(0..=20), 9), None);
+        assert_eq!(first_gap_of_len(&occupied, &(0..=20), 1), Some(3..=3));
+        assert_eq!(first_gap_of_len(&[], &(0..=20), 21), Some(0..=20));
+        assert_eq!(first_gap_of_len(&[], &(0..=20), 22), None);
+        assert_eq!(first_gap_of_len(&[0u8..=9], &(..), 246), Some(10..=255));
+        assert_eq!(first_gap_of_len(&[0u8..=9], &(..), 247), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "length must be positive")]
+    fn test_first_gap_of_len_zero() {
+        first_gap_of_len(&[0..=2], &(0..=20), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_segment_cover() {
+        assert_eq!(
+            segment_cover(&[0..=2, 6..=8], &(0..=10)),
+            [
+                (0..=2, true),
+                (3..=5, false),
+                (6..=8, true),
+                (9..=10, false)
+            ]
+        );
+        // leading and trailing gaps
+        assert_eq!(
+            segment_cover(&[3..=4], &(0..=10)),
+            [(0..=2, false), (3..=4, true), (5..=10, false)]
+        );
+        assert_eq!(
+            segment_cover(&[6..=8, 3..=4, 4..=5], &(0..=10)),
+            [(0..=2, false), (3..=8, true), (9..=10, false)]
+        );
+        // ranges are restricted to the universe
+        assert_eq!(
+            segment_cover(&[-5..=2, 9..=20], &(0..=10)),
+            [(0..=2, true), (3..=8, false), (9..=10, true)]
+        );
+        assert_eq!(
+            segment_cover(&[-5..=-1, 20..=30], &(0..=10)),
+            [(0..=10, false)]
+        );
+        assert_eq!(segment_cover(&[0..=10], &(0..=10)), [(0..=10, true)]);
+        assert_eq!(segment_cover(&[], &(0..=10)), [(0..=10, false)]);
+        assert!(segment_cover(&[0..=3], &(5..=4)).is_empty());
+        assert_eq!(
+            segment_cover(&[250..=255], &(..)),
+            [(0u8..=249, false), (250..=255, true)]
+        );
+    }
+}
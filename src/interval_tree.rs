@@ -0,0 +1,178 @@
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+
+use crate::{BasicNum, RangeUtil};
+
+/// A static index of (possibly overlapping) ranges with values for overlap queries in `O(log n + k)`
+///
+/// The entries are sorted by their start and form an implicit balanced tree: the root of `lo..hi` is the middle entry, augmented with the maximum end of its subtree, so subtrees ending before a query can be skipped.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalTree<T, V> {
+    entries: Vec<(RangeInclusive<T>, V)>,
+    /// `max_ends[mid]` is the maximum end of the subtree rooted at `mid`
+    max_ends: Vec<T>,
+}
+impl<T: Ord + Clone + BasicNum, V> IntervalTree<T, V> {
+    /// Builds the tree from `(range, value)` pairs, empty ranges are dropped
+    pub fn build<R: RangeUtil<T>>(items: impl IntoIterator<Item = (R, V)>) -> Self {
+        let mut entries = items
+            .into_iter()
+            .filter_map(|(range, value)| Some((range.normalize()?, value)))
+            .collect::<Vec<_>>();
+        entries.sort_by(|(a, _), (b, _)| a.cmp_by_start(b).then_with(|| a.cmp_by_end(b)));
+        let mut max_ends = entries
+            .iter()
+            .map(|(r, _)| r.end().clone())
+            .collect::<Vec<_>>();
+        Self::augment(&entries, &mut max_ends, 0, entries.len());
+        Self { entries, max_ends }
+    }
+    /// Sets the maximum ends of the subtree `lo..hi`, `None` if it is empty
+    fn augment(
+        entries: &[(RangeInclusive<T>, V)],
+        max_ends: &mut [T],
+        lo: usize,
+        hi: usize,
+    ) -> Option<T> {
+        if lo >= hi {
+            return None;
+        }
+        let mid = lo + (hi - lo) / 2;
+        let left = Self::augment(entries, max_ends, lo, mid);
+        let right = Self::augment(entries, max_ends, mid + 1, hi);
+        let max = [left, right]
+            .into_iter()
+            .flatten()
+            .fold(entries[mid].0.end().clone(), T::max);
+        max_ends[mid] = max.clone();
+        Some(max)
+    }
+    /// All entries whose range includes `x`, sorted by their start
+    pub fn query_point(&self, x: &T) -> Vec<(&RangeInclusive<T>, &V)> {
+        self.query_range(&(x.clone()..=x.clone()))
+    }
+    /// All entries whose range intersects `range`, sorted by their start
+    pub fn query_range(&self, range: &impl RangeUtil<T>) -> Vec<(&RangeInclusive<T>, &V)> {
+        let mut found = Vec::new();
+        if let Some(range) = range.normalize() {
+            self.collect(&range, 0, self.entries.len(), &mut found);
+        }
+        found
+    }
+    fn collect<'a>(
+        &'a self,
+        range: &RangeInclusive<T>,
+        lo: usize,
+        hi: usize,
+        found: &mut Vec<(&'a RangeInclusive<T>, &'a V)>,
+    ) {
+        let mid = lo + (hi - lo) / 2;
+        // nothing in this subtree reaches `range`
+        if lo >= hi || &self.max_ends[mid] < range.start() {
+            return;
+        }
+        self.collect(range, lo, mid, found);
+        let (entry, value) = &self.entries[mid];
+        // the entries at and after `mid` start after `range`
+        if entry.start() > range.end() {
+            return;
+        }
+        if entry.intersects(range) {
+            found.push((entry, value));
+        }
+        self.collect(range, mid + 1, hi, found);
+    }
+    /// Whether the tree contains no entries
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+    /// All entries, sorted by their start
+    pub fn iter(&self) -> impl Iterator<Item = (&RangeInclusive<T>, &V)> {
+        self.entries.iter().map(|(r, v)| (r, v))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{ops::RangeInclusive, vec::Vec};
+
+    use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+    use super::IntervalTree;
+    use crate::RangeUtil;
+
+    fn brute_force(
+        items: &[(RangeInclusive<i32>, usize)],
+        range: &RangeInclusive<i32>,
+    ) -> Vec<usize> {
+        let mut found = items
+            .iter()
+            .filter(|(r, _)| r.intersects(range))
+            .map(|(_, v)| *v)
+            .collect::<Vec<_>>();
+        found.sort_unstable();
+        found
+    }
+
+    fn values(found: Vec<(&RangeInclusive<i32>, &usize)>) -> Vec<usize> {
+        let mut values = found.into_iter().map(|(_, &v)| v).collect::<Vec<_>>();
+        values.sort_unstable();
+        values
+    }
+
+    #[test]
+    fn test_query() {
+        let tree =
+            IntervalTree::build([(0..=10, 'a'), (5..=6, 'b'), (8..=20, 'c'), (30..=40, 'd')]);
+        let found = |x| {
+            tree.query_point(&x)
+                .into_iter()
+                .map(|(_, &v)| v)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(found(5), ['a', 'b']);
+        assert_eq!(found(9), ['a', 'c']);
+        assert_eq!(found(11), ['c']);
+        assert_eq!(found(25), []);
+        assert_eq!(found(40), ['d']);
+        assert_eq!(found(-1), []);
+        let found = |r: RangeInclusive<i32>| {
+            tree.query_range(&r)
+                .into_iter()
+                .map(|(_, &v)| v)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(found(7..=29), ['a', 'c']);
+        assert_eq!(found(21..=29), []);
+        assert_eq!(found(-10..=100), ['a', 'b', 'c', 'd']);
+        assert_eq!(tree.query_range(&(7..7)), []);
+
+        let empty = IntervalTree::<i32, ()>::build(Vec::<(RangeInclusive<i32>, ())>::new());
+        assert!(empty.is_empty());
+        assert_eq!(empty.query_point(&0), []);
+        assert_eq!(IntervalTree::build([(3..3, ())]).iter().count(), 0);
+    }
+
+    #[test]
+    fn test_brute_force() {
+        let mut rng = SmallRng::seed_from_u64(42);
+        for n in [1, 2, 3, 10, 100, 1000] {
+            let items = (0..n)
+                .map(|i| {
+                    let start = rng.random_range(-1000..1000);
+                    (start..=start + rng.random_range(0..200), i)
+                })
+                .collect::<Vec<_>>();
+            let tree = IntervalTree::build(items.clone());
+            for _ in 0..200 {
+                let x = rng.random_range(-1200..1200);
+                assert_eq!(values(tree.query_point(&x)), brute_force(&items, &(x..=x)));
+                let range = x..=x + rng.random_range(0..100);
+                assert_eq!(
+                    values(tree.query_range(&range)),
+                    brute_force(&items, &range)
+                );
+            }
+        }
+    }
+}
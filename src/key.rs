@@ -0,0 +1,64 @@
+use crate::BasicNum;
+
+/// A range in canonical form, usable as a `HashMap`/`BTreeMap` key, see `RangeUtil::key`
+///
+/// Ranges covering the same elements have equal keys regardless of their bounds, e.g. `(0..4).key() == (0..=3).key()`; all empty ranges have the same key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RangeKey<T> {
+    /// `(starts_at(), ends_at())`, `None` if empty
+    bounds: Option<(T, T)>,
+}
+impl<T: Ord + Clone + BasicNum> RangeKey<T> {
+    pub(crate) fn new(start: T, end: T) -> Self {
+        Self {
+            bounds: (start <= end).then_some((start, end)),
+        }
+    }
+    /// The first element, `None` if empty
+    pub fn start(&self) -> Option<&T> {
+        self.bounds.as_ref().map(|(start, _)| start)
+    }
+    /// The last element, `None` if empty
+    pub fn end(&self) -> Option<&T> {
+        self.bounds.as_ref().map(|(_, end)| end)
+    }
+    /// Whether the key is the one of empty ranges
+    pub fn is_empty(&self) -> bool {
+        self.bounds.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, ops::Bound};
+
+    use crate::RangeUtil;
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_key() {
+        assert_eq!((0..4).key(), (0..=3).key());
+        assert_eq!((..4u8).key(), (0..=3).key());
+        assert_eq!((250u8..).key(), (250..=255).key());
+        assert_eq!(
+            (Bound::Excluded(0), Bound::Excluded(4)).key(),
+            (1..=3).key()
+        );
+        assert_ne!((0..4).key(), (0..=4).key());
+        assert_eq!((5..5).key(), (3..=0).key());
+        assert!((5..5).key().is_empty());
+        assert_eq!((2..5).key().start(), Some(&2));
+        assert_eq!((2..5).key().end(), Some(&4));
+        assert_eq!((5..5).key().start(), None);
+        assert_eq!((..0u8).key(), (5u8..5).key());
+        assert_ne!((..0u8).key(), (0..=0).key());
+
+        let mut set = HashSet::new();
+        assert!(set.insert((0..4).key()));
+        assert!(!set.insert((0..=3).key()));
+        assert!(set.insert((0..=4).key()));
+        assert!(set.insert((5..5).key()));
+        assert!(!set.insert((7..7).key()));
+        assert_eq!(set.len(), 3);
+    }
+}
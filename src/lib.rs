@@ -1,4 +1,76 @@
-use std::ops::{Bound, RangeBounds, RangeInclusive, Sub};
+#![no_std]
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(test)]
+extern crate std;
+// the derive macro refers to `::range_utils`
+#[cfg(all(test, feature = "derive"))]
+extern crate self as range_utils;
+
+use core::{
+    cmp::{Ordering, Reverse},
+    iter::Rev,
+    num::{
+        NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+        NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+    },
+    ops::{Add, Bound, Div, Mul, Range, RangeBounds, RangeInclusive, Rem, Sub},
+};
+
+#[cfg(all(feature = "rand", feature = "alloc"))]
+use alloc::collections::BTreeSet;
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String, vec, vec::Vec};
+
+mod arith;
+mod chunks;
+mod coverage;
+mod error;
+#[cfg(feature = "float")]
+mod float;
+#[cfg(feature = "arbitrary")]
+mod fuzzing;
+mod gaps;
+#[cfg(feature = "alloc")]
+mod interval_tree;
+mod key;
+mod parse;
+#[cfg(feature = "alloc")]
+mod range_map;
+#[cfg(feature = "alloc")]
+mod range_set;
+mod relation;
+#[cfg(feature = "chrono")]
+mod time_delta;
+mod usize_range;
+mod values;
+mod wrapping;
+pub use chunks::{Chunks, Windows};
+pub use coverage::count_overlapping;
+#[cfg(feature = "alloc")]
+pub use coverage::{covered_count, flatten_weighted, max_coverage};
+pub use error::{IntersectError, RangeError};
+#[cfg(feature = "float")]
+pub use float::{BasicFloat, FloatRangeUtil};
+#[cfg(feature = "arbitrary")]
+pub use fuzzing::ArbitraryRange;
+#[cfg(feature = "alloc")]
+pub use gaps::segment_cover;
+pub use gaps::{first_gap_of_len, iter_gaps, Gaps};
+#[cfg(feature = "alloc")]
+pub use interval_tree::IntervalTree;
+pub use key::RangeKey;
+pub use parse::{parse_range, ParseRangeError};
+#[cfg(feature = "alloc")]
+pub use range_map::{Entry, OccupiedEntry, RangeMap, VacantEntry};
+#[cfg(feature = "alloc")]
+pub use range_set::RangeSet;
+#[cfg(feature = "derive")]
+pub use range_utils_derive::BasicNum;
+pub use relation::RangeRelation;
+pub use usize_range::UsizeRangeUtil;
+pub use values::{FiniteRange, RangeValues, StepValues};
+pub use wrapping::WrappingRange;
 
 /// Basic operations (increase decrease) for numbers
 pub trait BasicNum {
@@ -6,6 +78,45 @@ pub trait BasicNum {
     const MAX_VALUE: Self;
     fn dec(&self) -> Self;
     fn inc(&self) -> Self;
+    /// The number of steps from `MIN_VALUE` to `self`, used for counting elements without overflowing `Self`
+    fn offset_from_min(&self) -> u128;
+    /// The inverse of `offset_from_min`, `offset` must not be greater than `MAX_VALUE.offset_from_min()`
+    fn from_offset(offset: u128) -> Self;
+    /// `dec`, but `None` instead of overflowing at `MIN_VALUE`
+    fn checked_dec(&self) -> Option<Self>
+    where
+        Self: PartialEq + Sized,
+    {
+        (self != &Self::MIN_VALUE).then(|| self.dec())
+    }
+    /// `inc`, but `None` instead of overflowing at `MAX_VALUE`
+    fn checked_inc(&self) -> Option<Self>
+    where
+        Self: PartialEq + Sized,
+    {
+        (self != &Self::MAX_VALUE).then(|| self.inc())
+    }
+    /// `dec`, but staying at `MIN_VALUE` instead of overflowing
+    fn saturating_dec(&self) -> Self
+    where
+        Self: PartialEq + Sized,
+    {
+        self.checked_dec().unwrap_or(Self::MIN_VALUE)
+    }
+    /// `inc`, but staying at `MAX_VALUE` instead of overflowing
+    fn saturating_inc(&self) -> Self
+    where
+        Self: PartialEq + Sized,
+    {
+        self.checked_inc().unwrap_or(Self::MAX_VALUE)
+    }
+}
+/// `BasicNum` types with an arithmetic zero and one, i.e. the primitive integer types
+///
+/// These are separate from `BasicNum` since the `NonZero` types (and `char`) don't have a zero.
+pub trait BasicInt: BasicNum {
+    const ZERO: Self;
+    const ONE: Self;
 }
 macro_rules! impl_primitive_basic_num {
     ($($t:ty),*) => {
@@ -19,12 +130,123 @@ macro_rules! impl_primitive_basic_num {
                 fn inc(&self) -> Self {
                     self + 1
                 }
+                fn checked_dec(&self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+                fn checked_inc(&self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+                fn saturating_dec(&self) -> Self {
+                    self.saturating_sub(1)
+                }
+                fn saturating_inc(&self) -> Self {
+                    self.saturating_add(1)
+                }
+                fn offset_from_min(&self) -> u128 {
+                    // going through `i128` works for signed and unsigned types, wrapping is intended for `u128`/`i128`
+                    (*self as i128).wrapping_sub(Self::MIN as i128) as u128
+                }
+                fn from_offset(offset: u128) -> Self {
+                    (Self::MIN as i128).wrapping_add(offset as i128) as Self
+                }
+            }
+            impl BasicInt for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
             }
         )*
     };
 }
-// no f32/f64 since range useless on these
+// no f32/f64 since range useless on these, see `FloatRangeUtil` (feature `float`) instead
 impl_primitive_basic_num!(usize, isize, u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+macro_rules! impl_non_zero_basic_num {
+    ($($t:ty => $inner:ty),*) => {
+        $(
+            /// Skips zero, e.g. `-1` is followed by `1` for signed types
+            ///
+            /// Decrementing `1` of an unsigned type overflows (like for primitives).
+            impl BasicNum for $t {
+                const MIN_VALUE: Self = Self::MIN;
+                const MAX_VALUE: Self = Self::MAX;
+                fn dec(&self) -> Self {
+                    let x = self.get().dec();
+                    Self::new(x).unwrap_or_else(|| Self::new(x.dec()).unwrap())
+                }
+                fn inc(&self) -> Self {
+                    let x = self.get().inc();
+                    Self::new(x).unwrap_or_else(|| Self::new(x.inc()).unwrap())
+                }
+                fn offset_from_min(&self) -> u128 {
+                    // skipping zero, which is below all positive values
+                    self.get().offset_from_min() - (self.get() > 0) as u128
+                }
+                fn from_offset(offset: u128) -> Self {
+                    let zero = (0 as $inner).offset_from_min();
+                    Self::new(<$inner>::from_offset(offset + (offset >= zero) as u128)).unwrap()
+                }
+            }
+        )*
+    };
+}
+impl_non_zero_basic_num!(
+    NonZeroUsize => usize,
+    NonZeroIsize => isize,
+    NonZeroU8 => u8,
+    NonZeroU16 => u16,
+    NonZeroU32 => u32,
+    NonZeroU64 => u64,
+    NonZeroU128 => u128,
+    NonZeroI8 => i8,
+    NonZeroI16 => i16,
+    NonZeroI32 => i32,
+    NonZeroI64 => i64,
+    NonZeroI128 => i128
+);
+/// Steps through the unicode scalar values, skipping the surrogates `0xD800..=0xDFFF`
+impl BasicNum for char {
+    const MIN_VALUE: Self = '\0';
+    const MAX_VALUE: Self = char::MAX;
+    fn dec(&self) -> Self {
+        match self {
+            '\u{E000}' => '\u{D7FF}',
+            // the only invalid value (a surrogate) is handled above
+            c => char::from_u32(*c as u32 - 1).unwrap(),
+        }
+    }
+    fn inc(&self) -> Self {
+        match self {
+            '\u{D7FF}' => '\u{E000}',
+            c => char::from_u32(*c as u32 + 1).expect("overflow when incrementing char::MAX"),
+        }
+    }
+    fn offset_from_min(&self) -> u128 {
+        let x = *self as u32;
+        (if x > 0xDFFF { x - 0x800 } else { x }) as u128
+    }
+    fn from_offset(offset: u128) -> Self {
+        let x = offset as u32;
+        char::from_u32(if x >= 0xD800 { x + 0x800 } else { x }).unwrap()
+    }
+}
+/// Descending order, i.e. `inc`/`dec` and `MIN_VALUE`/`MAX_VALUE` of the inner type are swapped, e.g. `Reverse(5).inc() == Reverse(4)`
+///
+/// `Reverse<T>` doesn't implement `Add`/`Sub`, so methods like `len` aren't available; use `cardinality` for counting.
+impl<T: BasicNum> BasicNum for Reverse<T> {
+    const MIN_VALUE: Self = Reverse(T::MAX_VALUE);
+    const MAX_VALUE: Self = Reverse(T::MIN_VALUE);
+    fn dec(&self) -> Self {
+        Reverse(self.0.inc())
+    }
+    fn inc(&self) -> Self {
+        Reverse(self.0.dec())
+    }
+    fn offset_from_min(&self) -> u128 {
+        T::MAX_VALUE.offset_from_min() - self.0.offset_from_min()
+    }
+    fn from_offset(offset: u128) -> Self {
+        Reverse(T::from_offset(T::MAX_VALUE.offset_from_min() - offset))
+    }
+}
 
 /// Note that this implementation is inefficient if cloning is extremely expensive.
 pub trait RangeUtil<T: Ord + Clone + BasicNum>: Sized + Clone {
@@ -32,23 +254,546 @@ pub trait RangeUtil<T: Ord + Clone + BasicNum>: Sized + Clone {
     fn starts_at(&self) -> T;
     /// End bound inclusive
     fn ends_at(&self) -> T;
+    /// Whether the start is unbounded (e.g. `..3`), unlike `starts_at() == T::MIN_VALUE` this is `false` for `T::MIN_VALUE..3`
+    ///
+    /// Ranges are bounded unless the implementation says otherwise, the implementation for `RangeBounds` checks `start_bound()` without cloning.
+    fn is_start_unbounded(&self) -> bool {
+        false
+    }
+    /// Whether the end is unbounded (e.g. `3..`), see `is_start_unbounded`
+    fn is_end_unbounded(&self) -> bool {
+        false
+    }
 
-    /// The length of the range
+    /// The number of elements in the range, e.g. `(0..=3).len() == Some(4)`
+    ///
+    /// Returns `None` if the range is empty, see `is_empty` for just checking whether a range contains any elements.
+    ///
+    /// Also returns `None` if the number of elements doesn't fit into `T`, e.g. `RangeUtil::<u8>::len(&(..))` would be `256` and `RangeUtil::<i8>::len(&(-100..=100))` would be `201`. Use `cardinality` if this may happen.
     fn len(&self) -> Option<T>
     where
         T: Sub<Output = T>,
     {
-        (self.ends_at() >= self.starts_at()).then(|| self.ends_at().inc() - self.starts_at())
+        if self.is_empty() {
+            return None;
+        }
+        // counting with offsets so nothing overflows, `x - x` is the zero of `T`
+        let start = self.starts_at();
+        let count = (self.ends_at().offset_from_min() - start.offset_from_min()).checked_add(1)?;
+        let offset = (start.clone() - start)
+            .offset_from_min()
+            .checked_add(count)?;
+        (offset <= T::MAX_VALUE.offset_from_min()).then(|| T::from_offset(offset))
+    }
+    /// The number of elements in the range as `u128`, e.g. `(i64::MIN..=i64::MAX).cardinality() == 2u128.pow(64)`
+    ///
+    /// Unlike `len` this never overflows for types up to 64 bits; the only count not fitting into `u128` is the one of the full range of a 128-bit type, which saturates at `u128::MAX`. Empty ranges have a cardinality of `0`.
+    fn cardinality(&self) -> u128 {
+        if self.is_empty() {
+            return 0;
+        }
+        (self.ends_at().offset_from_min() - self.starts_at().offset_from_min()).saturating_add(1)
+    }
+    /// The element in the middle of the range, rounded down, e.g. `(0..=10).midpoint() == Some(5)` and `(0..=9).midpoint() == Some(4)`
+    ///
+    /// `None` if the range is empty. This is computed with offsets (see `BasicNum::offset_from_min`), so it can't overflow.
+    fn midpoint(&self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let (start, end) = (
+            self.starts_at().offset_from_min(),
+            self.ends_at().offset_from_min(),
+        );
+        Some(T::from_offset(start + (end - start) / 2))
+    }
+    /// A uniformly random element of the range, e.g. `(1..=6).sample_uniform(&mut rng)` rolls a die
+    ///
+    /// `None` if the range is empty. The element is drawn as an offset (see `BasicNum::offset_from_min`), so this works for the full range of any type, e.g. `i64::MIN..=i64::MAX`.
+    #[cfg(feature = "rand")]
+    fn sample_uniform<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let (start, end) = (
+            self.starts_at().offset_from_min(),
+            self.ends_at().offset_from_min(),
+        );
+        Some(T::from_offset(start + rng.random_range(0..=end - start)))
+    }
+    /// `k` distinct uniformly random elements of the range in ascending order, or all elements if the range has at most `k` elements
+    ///
+    /// This is Floyd's algorithm on the offsets (see `BasicNum::offset_from_min`), so it draws exactly `k` random numbers and doesn't iterate over the range.
+    #[cfg(all(feature = "rand", feature = "alloc"))]
+    fn sample_distinct<R: rand::Rng + ?Sized>(&self, rng: &mut R, k: usize) -> Vec<T> {
+        if self.is_empty() || k == 0 {
+            return Vec::new();
+        }
+        let (start, end) = (
+            self.starts_at().offset_from_min(),
+            self.ends_at().offset_from_min(),
+        );
+        let span = end - start;
+        if k as u128 > span {
+            return self.values().collect();
+        }
+        let mut chosen = BTreeSet::new();
+        for j in span - (k as u128 - 1)..=span {
+            let t = rng.random_range(0..=j);
+            if !chosen.insert(t) {
+                chosen.insert(j);
+            }
+        }
+        chosen
+            .into_iter()
+            .map(|offset| T::from_offset(start + offset))
+            .collect()
+    }
+    /// The element at the (zero-based) `index`, i.e. `starts_at() + index`, e.g. `(10..=20).nth(3) == Some(13)`
+    ///
+    /// `None` if `index` is negative or not less than the length.
+    fn nth(&self, index: T) -> Option<T>
+    where
+        T: BasicInt + Add<Output = T> + Sub<Output = T>,
+    {
+        if index < T::ZERO {
+            return None;
+        }
+        let (start, end) = self.to_inclusive().into_inner();
+        arith::checked_add(start, index).filter(|x| x <= &end)
+    }
+    /// The (zero-based) index of `value`, i.e. `value - starts_at()`, e.g. `(10..=20).position_of(&13) == Some(3)`
+    ///
+    /// This is the inverse of `nth`, `None` if the range doesn't include `value` (or if the index doesn't fit into `T`).
+    fn position_of(&self, value: &T) -> Option<T>
+    where
+        T: BasicInt + Add<Output = T> + Sub<Output = T>,
+    {
+        self.includes(value)
+            .then(|| arith::checked_sub(value.clone(), self.starts_at()))
+            .flatten()
+    }
+    /// The first element for which `pred` is `true`, e.g. `(0..=1000).partition_point(|x| x * x >= 500) == Some(23)`
+    ///
+    /// `pred` has to be monotonic over the range (`false` for a prefix, then `true`), this is a binary search with `midpoint`, so it doesn't iterate over the elements. `None` if `pred` is `false` for all elements (or if the range is empty).
+    fn partition_point<P: Fn(&T) -> bool>(&self, pred: P) -> Option<T> {
+        if self.is_empty() || !pred(&self.ends_at()) {
+            return None;
+        }
+        // `pred` is `true` at the end of `window`
+        let mut window = self.to_inclusive();
+        while window.start() < window.end() {
+            let mid = window.midpoint()?;
+            window = if pred(&mid) {
+                window.start().clone()..=mid
+            } else {
+                // `mid` is below the end, so this can't overflow
+                mid.inc()..=window.end().clone()
+            };
+        }
+        Some(window.start().clone())
+    }
+    /// Whether the range contains no elements, i.e. `starts_at() > ends_at()`, e.g. `0..0` or `3..=0`
+    ///
+    /// This is equivalent to `len().is_none()`, but doesn't require `T: Sub`. Note that `Range` and `RangeInclusive` have an inherent `is_empty` with the same meaning, so `RangeUtil::is_empty(&range)` is only needed where the inherent method isn't available (e.g. generic code or `RangeFrom`).
+    fn is_empty(&self) -> bool {
+        self.starts_at() > self.ends_at()
+    }
+    /// The resolved range `starts_at()..=ends_at()`, e.g. `(2..5).to_inclusive() == 2..=4` and `(3u8..).to_inclusive() == 3..=255`
+    ///
+    /// This normalizes any `RangeBounds` into one concrete type, `to_exclusive` is the half-open counterpart.
+    fn to_inclusive(&self) -> RangeInclusive<T> {
+        self.starts_at()..=self.ends_at()
+    }
+    /// Same as `to_inclusive`, but `None` if the range is empty, e.g. `(5..=3).normalize() == None` and `(..).normalize() == Some(0..=255)` for `u8`
+    fn normalize(&self) -> Option<RangeInclusive<T>> {
+        (!self.is_empty()).then(|| self.to_inclusive())
+    }
+    /// The half-open range `starts_at()..ends_at().inc()`, e.g. `(3..=7).to_exclusive() == Some(3..8)`, so results like `intersection` can be used as `Range<T>`
+    ///
+    /// `None` if `ends_at()` is `T::MAX_VALUE`, since the exclusive end would overflow. Empty ranges stay empty.
+    fn to_exclusive(&self) -> Option<Range<T>> {
+        let (start, end) = self.to_inclusive().into_inner();
+        Some(start..end.checked_inc()?)
+    }
+    /// The range `f(starts_at())..=f(ends_at())`, e.g. `(0usize..=5).map_bounds(|x| x as i64 * 2) == 0..=10`
+    ///
+    /// Using different name to prevent name clash with `Iterator::map`. `f` should be monotonic (non-decreasing), otherwise the result may be reversed (i.e. empty).
+    fn map_bounds<U, F: Fn(T) -> U>(&self, f: F) -> RangeInclusive<U> {
+        let (start, end) = self.to_inclusive().into_inner();
+        f(start)..=f(end)
     }
     /// Using different name to prevent name clash, this does not require `Self: RangeBound`
     fn includes(&self, x: &T) -> bool {
-        &self.starts_at() <= x && x <= &self.ends_at()
+        let (start, end) = self.to_inclusive().into_inner();
+        &start <= x && x <= &end
+    }
+    /// Whether every item `includes`, stops at the first item outside the range, e.g. `(0..=10).contains_all([1, 5, 10])`
+    ///
+    /// `true` for an empty iterator.
+    fn contains_all<I: IntoIterator<Item = T>>(&self, items: I) -> bool {
+        let (start, end) = self.to_inclusive().into_inner();
+        items.into_iter().all(|x| start <= x && x <= end)
+    }
+    /// Whether at least one item `includes`, stops at the first item inside the range, e.g. `(0..=10).contains_any([-1, 5, 20])`
+    ///
+    /// `false` for an empty iterator.
+    fn contains_any<I: IntoIterator<Item = T>>(&self, items: I) -> bool {
+        let (start, end) = self.to_inclusive().into_inner();
+        items.into_iter().any(|x| start <= x && x <= end)
+    }
+    /// Whether each item `includes`, in the order of `items`, e.g. `(0..=10).includes_mask([5, 20, 0]) == [true, false, true]`
+    #[cfg(feature = "alloc")]
+    fn includes_mask<I: IntoIterator<Item = T>>(&self, items: I) -> Vec<bool> {
+        let (start, end) = self.to_inclusive().into_inner();
+        items.into_iter().map(|x| start <= x && x <= end).collect()
+    }
+    /// The number of items the range `includes`, e.g. `(0..=10).includes_count([5, 20, 0]) == 2`
+    fn includes_count<I: IntoIterator<Item = T>>(&self, items: I) -> usize {
+        let (start, end) = self.to_inclusive().into_inner();
+        items
+            .into_iter()
+            .filter(|x| &start <= x && x <= &end)
+            .count()
+    }
+    /// Snaps `x` into the range, e.g. `(0..=10).clamp_value(15) == 10` and `(0..=10).clamp_value(5) == 5`
+    ///
+    /// For empty ranges `x` is returned unchanged, use `checked_clamp_value` to detect that case.
+    fn clamp_value(&self, x: T) -> T {
+        if self.is_empty() {
+            x
+        } else {
+            x.clamp(self.starts_at(), self.ends_at())
+        }
+    }
+    /// Same as `clamp_value`, but `None` if the range is empty
+    fn checked_clamp_value(&self, x: T) -> Option<T> {
+        (!self.is_empty()).then(|| self.clamp_value(x))
+    }
+    /// The element of the range nearest to `x`, i.e. `x` itself if it is included, otherwise `starts_at()`/`ends_at()` if `x` is below/above the range
+    ///
+    /// Same as `clamp_value` (so `x` for empty ranges), use `checked_closest` to detect that case.
+    fn closest(&self, x: &T) -> T {
+        self.clamp_value(x.clone())
+    }
+    /// Same as `closest`, but `None` if the range is empty
+    fn checked_closest(&self, x: &T) -> Option<T> {
+        self.checked_clamp_value(x.clone())
+    }
+    /// The number of steps from `x` to the nearest element of the range, e.g. `(5..=10).distance_to(&2) == Some(3)`, `0` if `x` is included
+    ///
+    /// `None` if the range is empty. The distance is computed with offsets (see `BasicNum::offset_from_min`), so it doesn't overflow even for the full range of a signed type.
+    fn distance_to(&self, x: &T) -> Option<u128> {
+        let closest = self.checked_closest(x)?;
+        Some(x.offset_from_min().abs_diff(closest.offset_from_min()))
+    }
+    /// The resolved range as an always iterable `FiniteRange`, e.g. `(3u8..).finite()` is `3..=255`, so `for x in (3u8..).finite()` stops at `u8::MAX`
+    fn finite(&self) -> FiniteRange<T> {
+        let (start, end) = self.to_inclusive().into_inner();
+        FiniteRange::new(start, end)
+    }
+    /// The canonical form of the range for hashing and comparing, e.g. `(0..4).key() == (0..=3).key()`
+    fn key(&self) -> RangeKey<T> {
+        let (start, end) = self.to_inclusive().into_inner();
+        RangeKey::new(start, end)
+    }
+    /// Iterates over all elements of the range in ascending order, e.g. `(0..=3).values()` yields `0, 1, 2, 3`
+    ///
+    /// This also works for ranges ending at `T::MAX_VALUE`, e.g. `(254u8..).values()` yields `254, 255`.
+    fn values(&self) -> RangeValues<T> {
+        let (start, end) = self.to_inclusive().into_inner();
+        RangeValues::new(start, end)
+    }
+    /// Iterates over all elements of the range in descending order, e.g. `(0..=3).values_rev()` yields `3, 2, 1, 0`
+    ///
+    /// This is the same as `values().rev()`, it also works for ranges starting at `T::MIN_VALUE`.
+    fn values_rev(&self) -> Rev<RangeValues<T>> {
+        self.values().rev()
+    }
+    /// Iterates over every `step`-th element of the range starting at `starts_at()`, e.g. `(0..=10).step_values(3)` yields `0, 3, 6, 9`
+    ///
+    /// Panics if `step` isn't positive.
+    fn step_values(&self, step: T) -> StepValues<T>
+    where
+        T: BasicInt + Add<Output = T> + Sub<Output = T>,
+    {
+        let (start, end) = self.to_inclusive().into_inner();
+        StepValues::new(start, end, step)
+    }
+    /// Compares the (inclusive) starts of both ranges, e.g. `(0..=10).cmp_by_start(&(3..=4)) == Ordering::Less`
+    fn cmp_by_start(&self, other: &impl RangeUtil<T>) -> Ordering {
+        self.starts_at().cmp(&other.starts_at())
+    }
+    /// Compares the (inclusive) ends of both ranges, e.g. `(0..=10).cmp_by_end(&(3..=4)) == Ordering::Greater`
+    fn cmp_by_end(&self, other: &impl RangeUtil<T>) -> Ordering {
+        self.ends_at().cmp(&other.ends_at())
+    }
+    /// Moves both ends of the range by `delta`, e.g. `(2..=4).shift(3) == 5..=7` and `(2..=4).shift(-3) == -1..=1`
+    ///
+    /// The ends saturate at `T::MIN_VALUE`/`T::MAX_VALUE` (so the length isn't preserved in that case), e.g. `(250u8..=255).shift(10) == 255..=255`; see `checked_shift`.
+    fn shift(&self, delta: T) -> RangeInclusive<T>
+    where
+        T: BasicInt + Add<Output = T> + Sub<Output = T>,
+    {
+        let (start, end) = self.to_inclusive().into_inner();
+        arith::saturating_add(start, delta.clone())..=arith::saturating_add(end, delta)
+    }
+    /// Same as `shift`, but `None` if either end would overflow
+    fn checked_shift(&self, delta: T) -> Option<RangeInclusive<T>>
+    where
+        T: BasicInt + Add<Output = T> + Sub<Output = T>,
+    {
+        let (start, end) = self.to_inclusive().into_inner();
+        Some(arith::checked_add(start, delta.clone())?..=arith::checked_add(end, delta)?)
+    }
+    /// Shifts the range by `by` inside `domain`, wrapping around its ends, e.g. `(8..=10).rotate(5, &(0..=11)) == [1..=3]` and `(8..=10).rotate(3, &(0..=11)) == [0..=1, 11..=11]`
+    ///
+    /// A range crossing the end of `domain` is split into two fragments, the fragments are sorted by their start. `by` may be negative (or larger than `domain`), only `by` modulo the number of elements of `domain` matters. Elements outside of `domain` are dropped first.
+    #[cfg(feature = "alloc")]
+    fn rotate(&self, by: T, domain: &impl RangeUtil<T>) -> Vec<RangeInclusive<T>>
+    where
+        T: BasicInt,
+    {
+        let Some(range) = self.intersection(domain) else {
+            return Vec::new();
+        };
+        let base = domain.starts_at().offset_from_min();
+        // the elements are handled as offsets `0..=last` from `domain.starts_at()`, `last + 1` may overflow
+        let last = domain.ends_at().offset_from_min() - base;
+        if range.cardinality() - 1 == last {
+            return vec![range];
+        }
+        let modulo = |x: u128| if last == u128::MAX { x } else { x % (last + 1) };
+        let zero = T::ZERO.offset_from_min();
+        let shift = if by >= T::ZERO {
+            modulo(by.offset_from_min() - zero)
+        } else {
+            // `-x` modulo `last + 1`
+            match modulo(zero - by.offset_from_min()) {
+                0 => 0,
+                x => last - x + 1,
+            }
+        };
+        // `(x + shift) % (last + 1)` for `x, shift <= last`, written so it can't overflow
+        let rotate = |x: T| {
+            let x = x.offset_from_min() - base;
+            let x = if x > last - shift {
+                x - (last - shift) - 1
+            } else {
+                x + shift
+            };
+            T::from_offset(base + x)
+        };
+        let (start, end) = range.into_inner();
+        let (start, end) = (rotate(start), rotate(end));
+        if start <= end {
+            vec![start..=end]
+        } else {
+            vec![domain.starts_at()..=end, start..=domain.ends_at()]
+        }
+    }
+    /// Multiplies both ends of the range by `factor`, e.g. `(2..=4).scale(3) == 6..=12` and `(2..=4).scale(-1) == -4..=-2`
+    ///
+    /// This is a geometric transform of the ends, not a resampling: the elements in between are not scaled, e.g. `(2..=4).scale(3)` contains `7`. The ends are swapped for a negative `factor`, a zero `factor` collapses the range to `0..=0` and empty ranges are returned unchanged. The ends saturate at `T::MIN_VALUE`/`T::MAX_VALUE`, see `checked_scale`.
+    fn scale(&self, factor: T) -> RangeInclusive<T>
+    where
+        T: BasicInt + Mul<Output = T> + Div<Output = T>,
+    {
+        if self.is_empty() {
+            return self.to_inclusive();
+        }
+        let (start, end) = (
+            arith::saturating_mul(self.starts_at(), factor.clone()),
+            arith::saturating_mul(self.ends_at(), factor.clone()),
+        );
+        if factor < T::ZERO {
+            end..=start
+        } else {
+            start..=end
+        }
+    }
+    /// Same as `scale`, but `None` if either end would overflow
+    fn checked_scale(&self, factor: T) -> Option<RangeInclusive<T>>
+    where
+        T: BasicInt + Mul<Output = T> + Div<Output = T>,
+    {
+        if self.is_empty() {
+            return Some(self.to_inclusive());
+        }
+        let (start, end) = (
+            arith::checked_mul(self.starts_at(), factor.clone())?,
+            arith::checked_mul(self.ends_at(), factor.clone())?,
+        );
+        Some(if factor < T::ZERO {
+            end..=start
+        } else {
+            start..=end
+        })
+    }
+    /// Rounds `starts_at()` down to a multiple of `alignment` and `ends_at()` up to one below a multiple, e.g. `(5..=17).align_outward(8) == 0..=23`
+    ///
+    /// This is `align_start_down` and `align_end_up` combined. The ends saturate at `T::MIN_VALUE`/`T::MAX_VALUE`, e.g. `(250u8..=251).align_outward(8) == 248..=255`, and empty ranges are returned unchanged. Panics if `alignment` isn't positive.
+    fn align_outward(&self, alignment: T) -> RangeInclusive<T>
+    where
+        T: BasicInt + Add<Output = T> + Sub<Output = T> + Rem<Output = T>,
+    {
+        let (start, _) = self.align_start_down(alignment.clone()).into_inner();
+        let (_, end) = self.align_end_up(alignment).into_inner();
+        start..=end
+    }
+    /// Rounds `starts_at()` down to a multiple of `alignment` and keeps `ends_at()`, e.g. `(5..=17).align_start_down(8) == 0..=17`
+    ///
+    /// The start saturates at `T::MIN_VALUE` and empty ranges are returned unchanged. Panics if `alignment` isn't positive.
+    fn align_start_down(&self, alignment: T) -> RangeInclusive<T>
+    where
+        T: BasicInt + Add<Output = T> + Sub<Output = T> + Rem<Output = T>,
+    {
+        assert!(alignment > T::ZERO, "alignment must be positive");
+        if self.is_empty() {
+            return self.to_inclusive();
+        }
+        let start = self.starts_at();
+        // `x - x % alignment` rounds towards zero, so it can't overflow
+        let rem = start.clone() % alignment.clone();
+        let base = start - rem.clone();
+        let start = if rem < T::ZERO {
+            arith::saturating_sub(base, alignment)
+        } else {
+            base
+        };
+        start..=self.ends_at()
+    }
+    /// Rounds `ends_at()` up to one below a multiple of `alignment` (so the end is exclusive at a multiple) and keeps `starts_at()`, e.g. `(5..=17).align_end_up(8) == 5..=23`
+    ///
+    /// The end saturates at `T::MAX_VALUE` and empty ranges are returned unchanged. Panics if `alignment` isn't positive.
+    fn align_end_up(&self, alignment: T) -> RangeInclusive<T>
+    where
+        T: BasicInt + Add<Output = T> + Sub<Output = T> + Rem<Output = T>,
+    {
+        assert!(alignment > T::ZERO, "alignment must be positive");
+        if self.is_empty() {
+            return self.to_inclusive();
+        }
+        let end = self.ends_at();
+        // `x - x % alignment` rounds towards zero, so it can't overflow
+        let rem = end.clone() % alignment.clone();
+        let base = end - rem.clone();
+        let end = if rem < T::ZERO {
+            // `base` is above `end`, so this can't overflow
+            base.dec()
+        } else {
+            arith::saturating_add(base, alignment.dec())
+        };
+        self.starts_at()..=end
+    }
+    /// Extends `ends_at()` until the number of elements is a multiple of `block`, e.g. `(0..=9).dilate_to_multiple(4) == 0..=11`
+    ///
+    /// `starts_at()` is kept, the end saturates at `T::MAX_VALUE` and empty ranges are returned unchanged. Panics if `block` isn't positive.
+    fn dilate_to_multiple(&self, block: T) -> RangeInclusive<T>
+    where
+        T: BasicInt,
+    {
+        assert!(block > T::ZERO, "block size must be positive");
+        if self.is_empty() {
+            return self.to_inclusive();
+        }
+        let (start, end) = (self.starts_at(), self.ends_at());
+        // working with offsets (see `cardinality`) so the span of e.g. `i8::MIN..=i8::MAX` doesn't overflow
+        let block = block.offset_from_min() - T::ZERO.offset_from_min();
+        let span = end.offset_from_min() - start.offset_from_min();
+        // the length is `span + 1`, so `block - 1 - span % block` elements are missing
+        let missing = block - 1 - span % block;
+        let last = end
+            .offset_from_min()
+            .saturating_add(missing)
+            .min(T::MAX_VALUE.offset_from_min());
+        start..=T::from_offset(last)
+    }
+    /// Extends both ends of the range outward by `by`, e.g. `(5..=10).grow(2) == 3..=12`
+    ///
+    /// The ends saturate at `T::MIN_VALUE`/`T::MAX_VALUE`, e.g. `(1u8..=10).grow(2) == 0..=12`.
+    fn grow(&self, by: T) -> RangeInclusive<T>
+    where
+        T: BasicInt + Add<Output = T> + Sub<Output = T>,
+    {
+        let (start, end) = self.to_inclusive().into_inner();
+        arith::saturating_sub(start, by.clone())..=arith::saturating_add(end, by)
+    }
+    /// Pulls both ends of the range inward by `by`, e.g. `(5..=10).shrink(2) == Some(7..=8)`
+    ///
+    /// `None` if the range would become empty, e.g. `(5..=10).shrink(3)`.
+    fn shrink(&self, by: T) -> Option<RangeInclusive<T>>
+    where
+        T: BasicInt + Add<Output = T> + Sub<Output = T>,
+    {
+        let (start, end) = self.to_inclusive().into_inner();
+        // overflowing means that there are no elements left
+        Some(arith::checked_add(start, by.clone())?..=arith::checked_sub(end, by)?)
+            .filter(|r| !r.is_empty())
+    }
+    /// Whether both ranges contain the same elements regardless of their bounds, e.g. `(0..4).range_eq(&(0..=3))`
+    ///
+    /// All empty ranges are equal, this is the same as comparing their `key`s.
+    fn range_eq(&self, other: &impl RangeUtil<T>) -> bool {
+        self.key() == other.key()
+    }
+    /// Classifies how both ranges relate, e.g. `(0..=5).relation(&(3..=9)) == RangeRelation::Overlapping`
+    ///
+    /// The first matching variant of `Equal`, `Contains`, `ContainedBy`, `Overlapping`, `Adjacent` and `Disjoint` is returned. If exactly one range is empty they are `Disjoint`.
+    fn relation(&self, other: &impl RangeUtil<T>) -> RangeRelation {
+        if self.range_eq(other) {
+            RangeRelation::Equal
+        } else if self.is_empty() || other.is_empty() {
+            RangeRelation::Disjoint
+        } else if self.contains_range(other) {
+            RangeRelation::Contains
+        } else if other.contains_range(self) {
+            RangeRelation::ContainedBy
+        } else if self.intersects(other) {
+            RangeRelation::Overlapping
+        } else if self.is_adjacent(other) {
+            RangeRelation::Adjacent
+        } else {
+            RangeRelation::Disjoint
+        }
+    }
+    /// Whether `other` lies entirely within `self`, e.g. `0..=10` contains `2..=5` while `0..=10` doesn't contain `5..=15`
+    ///
+    /// An empty `other` is contained in every range (even an empty one).
+    fn contains_range(&self, other: &impl RangeUtil<T>) -> bool {
+        other.is_empty()
+            || (!self.is_empty()
+                && self.starts_at() <= other.starts_at()
+                && other.ends_at() <= self.ends_at())
+    }
+    /// Whether every element of `self` is in `other`, i.e. `other.contains_range(self)`
+    fn is_subset_of(&self, other: &impl RangeUtil<T>) -> bool {
+        other.contains_range(self)
+    }
+    /// Whether every element of `other` is in `self`, i.e. `self.contains_range(other)`
+    fn is_superset_of(&self, other: &impl RangeUtil<T>) -> bool {
+        self.contains_range(other)
+    }
+    /// Whether `self` is a subset of `other` without both being equal, e.g. `1..=3` is a proper subset of `0..=3` while `0..=3` isn't
+    fn is_proper_subset_of(&self, other: &impl RangeUtil<T>) -> bool {
+        self.is_subset_of(other) && !other.is_subset_of(self)
+    }
+    /// Whether `self` is a superset of `other` without both being equal, e.g. `0..=3` is a proper superset of `1..=3` while `0..=3` isn't
+    fn is_proper_superset_of(&self, other: &impl RangeUtil<T>) -> bool {
+        other.is_proper_subset_of(self)
     }
     /// Whether two ranges intersect, e.g. `0..=3` and `1..=4` intersect while `0..=3` and `4..` don't
     ///
-    /// This also works for "different ranges", e.g. `0..=3` and `2..` returns `true`
+    /// This also works for "different ranges", e.g. `0..=3` and `2..` returns `true`. Empty ranges don't intersect any range.
     fn intersects(&self, other: &impl RangeUtil<T>) -> bool {
-        self.ends_at() >= other.starts_at() && self.starts_at() <= other.ends_at()
+        !self.is_empty()
+            && !other.is_empty()
+            && self.ends_at() >= other.starts_at()
+            && self.starts_at() <= other.ends_at()
+    }
+    /// Whether two ranges have no common elements, i.e. `!self.intersects(other)`, e.g. `0..=3` and `4..=7` are disjoint (adjacent ranges don't overlap)
+    fn is_disjoint(&self, other: &impl RangeUtil<T>) -> bool {
+        !self.intersects(other)
     }
     /// The intersection of two ranges, e.g. `0..=3` and `1..=4` is `1..=3`
     ///
@@ -57,6 +802,49 @@ pub trait RangeUtil<T: Ord + Clone + BasicNum>: Sized + Clone {
         self.intersects(other)
             .then(|| self.starts_at().max(other.starts_at())..=self.ends_at().min(other.ends_at()))
     }
+    /// The `intersection`, but reports why there is none, e.g. `0..=3` and `4..=7` is `Err(IntersectError::Adjacent)` while `0..=3` and `5..=7` is `Err(IntersectError::Disjoint)`
+    ///
+    /// A range is never adjacent to an empty range, so these are `Disjoint`.
+    fn checked_intersection(
+        &self,
+        other: &impl RangeUtil<T>,
+    ) -> Result<RangeInclusive<T>, IntersectError> {
+        self.intersection(other).ok_or(if self.is_adjacent(other) {
+            IntersectError::Adjacent
+        } else {
+            IntersectError::Disjoint
+        })
+    }
+    /// The number of elements in both ranges, e.g. `(0..=5).overlap_len(&(3..=10)) == Some(3)`
+    ///
+    /// This is the `len` of the `intersection`, so it is `None` if the ranges don't intersect.
+    fn overlap_len(&self, other: &impl RangeUtil<T>) -> Option<T>
+    where
+        T: Sub<Output = T>,
+    {
+        self.intersection(other)
+            .and_then(|common| RangeUtil::len(&common))
+    }
+    /// The Jaccard index of both ranges, i.e. the number of elements in both divided by the number of elements in either, e.g. `(0..=3).overlap_ratio(&(2..=5)) == 2.0 / 6.0`
+    ///
+    /// `1.0` for equal ranges, `0.0` for disjoint ranges (or if both are empty). The counting is done with `cardinality`, so this doesn't overflow.
+    fn overlap_ratio(&self, other: &impl RangeUtil<T>) -> f64 {
+        let common = self
+            .intersection(other)
+            .map_or(0, |common| common.cardinality());
+        let either = (self.cardinality() - common).saturating_add(other.cardinality());
+        if either == 0 {
+            0.0
+        } else {
+            common as f64 / either as f64
+        }
+    }
+    /// Restricts `self` to the window `bounds`, e.g. `(5..=15).clamp_range(&(0..=10)) == Some(5..=10)`
+    ///
+    /// This is the same as `intersection`, `None` if `self` lies completely outside of `bounds`.
+    fn clamp_range(&self, bounds: &impl RangeUtil<T>) -> Option<RangeInclusive<T>> {
+        self.intersection(bounds)
+    }
     /// The result of substracting `other` from `self`, e.g. `0..=3`\`1..=4` is `(0..=0, None)`
     ///
     /// If there are two sets representing the result, then the smaller range comes first. If only one range represents the result, then either result may be `None` (implementation detail, may change in the future).
@@ -67,74 +855,1298 @@ pub trait RangeUtil<T: Ord + Clone + BasicNum>: Sized + Clone {
         other: &impl RangeUtil<T>,
     ) -> (Option<RangeInclusive<T>>, Option<RangeInclusive<T>>) {
         let Some(other) = self.intersection(other) else {
-            return (
-                Some(self.starts_at().clone()..=self.ends_at().clone()),
-                None,
-            );
+            return (self.normalize(), None);
         };
         let (a, b) = (self.starts_at().clone(), self.ends_at().clone());
         let (c, d) = (other.start().clone(), other.ends_at().clone());
+        // `c`/`d` may be `MIN_VALUE`/`MAX_VALUE`, in which case there is nothing left on that side
         (
             (self.includes(&c))
-                .then(|| a..=c.dec())
+                .then(|| c.checked_dec())
+                .flatten()
+                .map(|c| a..=c)
                 .filter(|r| !r.is_empty()),
             self.includes(&d)
-                .then(|| d.inc()..=b)
+                .then(|| d.checked_inc())
+                .flatten()
+                .map(|d| d..=b)
                 .filter(|r| !r.is_empty()),
         )
     }
-}
-impl<T: Ord + Clone + BasicNum, R: RangeBounds<T> + Clone> RangeUtil<T> for R {
-    fn starts_at(&self) -> T {
-        match self.start_bound() {
-            Bound::Excluded(x) => x.inc(),
-            Bound::Included(x) => x.clone(),
-            Bound::Unbounded => T::MIN_VALUE,
+    /// Removes all `others` from the range, e.g. `0..=10` without `2..=3` and `6..=7` is `[0..=1, 4..=5, 8..=10]`
+    ///
+    /// The remaining fragments are disjoint and sorted by their start, empty if nothing remains.
+    #[cfg(feature = "alloc")]
+    fn setminus_all<R: RangeUtil<T>>(
+        &self,
+        others: impl IntoIterator<Item = R>,
+    ) -> Vec<RangeInclusive<T>> {
+        let mut remaining = RangeSet::new();
+        remaining.insert(self.clone());
+        for other in others {
+            remaining.remove(other);
         }
+        remaining.iter().cloned().collect()
     }
-    fn ends_at(&self) -> T {
-        match self.end_bound() {
-            Bound::Excluded(x) => x.dec(),
-            Bound::Included(x) => x.clone(),
-            Bound::Unbounded => T::MAX_VALUE,
+    /// Splits the range into the elements below `pivot` and the ones at or above it, e.g. `(0..=9).split_at(5)` is `(0..=4, 5..=9)`
+    ///
+    /// Empty sides are `None`, e.g. if `pivot` is outside of the range one side is the whole range.
+    fn split_at(&self, pivot: T) -> (Option<RangeInclusive<T>>, Option<RangeInclusive<T>>) {
+        let (start, end) = self.to_inclusive().into_inner();
+        // there is nothing below `MIN_VALUE`
+        let below = pivot
+            .checked_dec()
+            .map(|p| start.clone()..=p.min(end.clone()))
+            .filter(|r| !r.is_empty());
+        let above = Some(pivot.max(start)..=end).filter(|r| !r.is_empty());
+        (below, above)
+    }
+    /// Splits the range at each of the sorted `points` (`split_at` with many pivots), e.g. `(0..=10).split_on(&[3, 7])` is `[0..=2, 3..=6, 7..=10]`
+    ///
+    /// Each point starts a new sub-range. Points outside of the range (or not above the previous point) are ignored, so there are no empty sub-ranges.
+    #[cfg(feature = "alloc")]
+    fn split_on(&self, points: &[T]) -> Vec<RangeInclusive<T>> {
+        let mut parts = Vec::new();
+        let mut rest = Some(self.to_inclusive()).filter(|r| !r.is_empty());
+        for point in points {
+            let Some((below, above)) = rest.take().map(|r| r.split_at(point.clone())) else {
+                break;
+            };
+            parts.extend(below);
+            rest = above;
         }
+        parts.extend(rest);
+        parts
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use std::ops::RangeFull;
-
-    use crate::RangeUtil;
-
-    #[test]
-    fn test_intersection_range_inclusive() {
-        assert_eq!((0..=3).intersection(&(1..=2)), Some(1..=2));
-        assert_eq!((0..=3).intersection(&(1..=30)), Some(1..=3));
-        assert_eq!((0..=3).intersection(&(-10..1)), Some(0..=0));
-        assert_eq!((0..=3).intersection(&(-10..=1)), Some(0..=1));
-        assert_eq!((0..=3).intersection(&(-10..=-1)), None);
-        assert_eq!((0..=3).intersection(&(4..=10)), None);
+    /// Splits the range at the `partition_point` of `pred` into the elements where it is `false` and the ones where it is `true`, e.g. `(0..=9).bisect(|&x| x >= 5)` is `(Some(0..=4), Some(5..=9))`
+    ///
+    /// `pred` has to be monotonic, see `partition_point`. Empty sides are `None` like for `split_at`.
+    fn bisect<P: Fn(&T) -> bool>(
+        &self,
+        pred: P,
+    ) -> (Option<RangeInclusive<T>>, Option<RangeInclusive<T>>) {
+        match self.partition_point(pred) {
+            Some(pivot) => self.split_at(pivot),
+            None => ((!self.is_empty()).then(|| self.to_inclusive()), None),
+        }
     }
-
-    #[test]
-    fn test_difference_range_inclusive() {
-        assert_eq!((0..=3).setminus(&(4..=100)), (Some(0..=3), None));
-        assert_eq!((0..=3).setminus(&(-100..=-1)), (Some(0..=3), None));
-        assert_eq!((0..=3).setminus(&(1..=2)), (Some(0..=0), Some(3..=3)));
-        assert_eq!((0..=3).setminus(&(0..=2)), (None, Some(3..=3)));
-        assert_eq!((0..=3).setminus(&(1..=3)), (Some(0..=0), None));
+    /// Partitions the range into consecutive sub-ranges with `size` elements, e.g. `(0..=9).chunks(4)` yields `0..=3, 4..=7, 8..=9`
+    ///
+    /// The last chunk may be shorter, it always ends at `ends_at()`. Panics if `size` isn't positive.
+    fn chunks(&self, size: T) -> Chunks<T>
+    where
+        T: BasicInt + Add<Output = T> + Sub<Output = T>,
+    {
+        let (start, end) = self.to_inclusive().into_inner();
+        Chunks::new(start, end, size)
     }
-
-    #[test]
-    fn test_from_incl() {
-        assert_eq!((0..).starts_at(), 0);
-        assert_eq!((0..1).starts_at(), 0);
-        assert_eq!((0..=1).starts_at(), 0);
-        assert_eq!((..=10).starts_at(), 0usize);
-        assert_eq!((..=10).starts_at(), isize::MIN);
-        assert_eq!(RangeUtil::<usize>::starts_at(&RangeFull), 0);
-        assert_eq!(RangeUtil::<isize>::starts_at(&RangeFull), isize::MIN);
+    /// Sliding sub-ranges with `size` elements advancing by one, e.g. `(0..=4).windows(3)` yields `0..=2, 1..=3, 2..=4`
+    ///
+    /// Yields nothing if the range has less than `size` elements. Panics if `size` isn't positive.
+    fn windows(&self, size: T) -> Windows<T>
+    where
+        T: BasicInt + Add<Output = T> + Sub<Output = T>,
+    {
+        let (start, end) = self.to_inclusive().into_inner();
+        Windows::new(start, end, size)
+    }
+    /// Covers the range with consecutive tiles of `width` elements, e.g. `(0..=24).tile(10)` yields `0..=9, 10..=19, 20..=24`
+    ///
+    /// An alias of `chunks` under the name used for rulers and grids, see there.
+    fn tile(&self, width: T) -> Chunks<T>
+    where
+        T: BasicInt + Add<Output = T> + Sub<Output = T>,
+    {
+        self.chunks(width)
+    }
+    /// Partitions the range into `n` consecutive sub-ranges whose sizes differ by at most one, e.g. `(0..=9).split_into(3)` is `[0..=3, 4..=6, 7..=9]`
+    ///
+    /// The remainder is distributed to the first sub-ranges. If `n` is greater than the number of elements, every element is its own sub-range; there are no empty sub-ranges.
+    #[cfg(feature = "alloc")]
+    fn split_into(&self, n: usize) -> Vec<RangeInclusive<T>> {
+        let count = self.cardinality();
+        let parts = count.min(n as u128);
+        if parts == 0 {
+            return Vec::new();
+        }
+        let (size, remainder) = (count / parts, count % parts);
+        let mut start = self.starts_at().offset_from_min();
+        (0..parts)
+            .map(|i| {
+                let len = size + (i < remainder) as u128;
+                // the last part ends exactly at `ends_at()` (even if `cardinality` saturated)
+                let end = if i + 1 == parts {
+                    self.ends_at()
+                } else {
+                    T::from_offset(start + len - 1)
+                };
+                let part = T::from_offset(start)..=end;
+                start += len;
+                part
+            })
+            .collect()
+    }
+    /// The elements of `universe` not in `self`, i.e. `universe.setminus(self)`, e.g. `(2..=4).complement(&(0..=9))` is `(0..=1, 5..=9)`
+    ///
+    /// The result is always within `universe`, `(None, None)` if `self` covers the whole universe.
+    fn complement(
+        &self,
+        universe: &impl RangeUtil<T>,
+    ) -> (Option<RangeInclusive<T>>, Option<RangeInclusive<T>>) {
+        universe.setminus(self)
+    }
+    /// Whether two ranges are next to each other without intersecting, e.g. `0..=3` and `4..=7` are adjacent while `0..=3` and `3..=7` or `5..=7` aren't
+    ///
+    /// Empty ranges are never adjacent to any range.
+    fn is_adjacent(&self, other: &impl RangeUtil<T>) -> bool {
+        let (a, b) = (self.to_inclusive(), other.to_inclusive());
+        if a.is_empty() || b.is_empty() || a.intersects(&b) {
+            return false;
+        }
+        let touches = |lower: &RangeInclusive<T>, upper: &RangeInclusive<T>| {
+            lower.end().checked_inc().as_ref() == Some(upper.start())
+        };
+        touches(&a, &b) || touches(&b, &a)
+    }
+    /// The union of two ranges, e.g. `0..=3` and `2..=6` is `(0..=6, None)`
+    ///
+    /// Adjacent ranges are merged as well, e.g. `0..=3` and `4..=6` is `(0..=6, None)`. If the ranges are disjoint, then both are returned with the smaller range first, e.g. `0..=3` and `5..=6` is `(0..=3, 5..=6)`.
+    fn union(
+        &self,
+        other: &impl RangeUtil<T>,
+    ) -> (Option<RangeInclusive<T>>, Option<RangeInclusive<T>>) {
+        let (a, b) = (self.normalize(), other.normalize());
+        let (Some(mut a), Some(mut b)) = (a.clone(), b.clone()) else {
+            return (a.or(b), None);
+        };
+        if a.start() > b.start() {
+            core::mem::swap(&mut a, &mut b);
+        }
+        if a.intersects(&b) || a.is_adjacent(&b) {
+            let end = a.end().max(b.end()).clone();
+            (Some(a.start().clone()..=end), None)
+        } else {
+            (Some(a), Some(b))
+        }
+    }
+    /// The `union` if it is a single range, e.g. `0..=3` and `3..=6` is `Some(0..=6)`, but `0..=3` and `10..=12` is `None`
+    ///
+    /// Intersecting and adjacent ranges can be merged, an empty range merges into the other one. `None` if both are empty.
+    fn try_merge(&self, other: &impl RangeUtil<T>) -> Option<RangeInclusive<T>> {
+        match self.union(other) {
+            (merged, None) => merged,
+            (_, Some(_)) => None,
+        }
+    }
+    /// The smallest range containing both ranges (including any gap between them), e.g. `0..=2` and `7..=9` is `0..=9`
+    ///
+    /// Empty ranges are ignored, i.e. the other range is returned.
+    fn bounding(&self, other: &impl RangeUtil<T>) -> RangeInclusive<T> {
+        if other.is_empty() {
+            self.to_inclusive()
+        } else if self.is_empty() {
+            other.to_inclusive()
+        } else {
+            self.starts_at().min(other.starts_at())..=self.ends_at().max(other.ends_at())
+        }
+    }
+    /// The smallest range containing the range and `point`, e.g. `(3..=7).expand_to_include(10) == 3..=10`
+    ///
+    /// This is `bounding` with `point..=point`, so an empty range becomes `point..=point`.
+    fn expand_to_include(&self, point: T) -> RangeInclusive<T> {
+        self.bounding(&(point.clone()..=point))
+    }
+    /// The elements in exactly one of both ranges, e.g. `0..=3` and `2..=5` is `(0..=1, 4..=5)`
+    ///
+    /// The smaller range comes first (as in `setminus`). Disjoint ranges are returned unchanged and ordered by their start, even if they are adjacent, e.g. `(0..=3).symmetric_difference(&(4..=6)) == (Some(0..=3), Some(4..=6))`; an empty range is `None` and comes last.
+    fn symmetric_difference(
+        &self,
+        other: &impl RangeUtil<T>,
+    ) -> (Option<RangeInclusive<T>>, Option<RangeInclusive<T>>) {
+        if !self.intersects(other) {
+            return match (self.normalize(), other.normalize()) {
+                (Some(a), Some(b)) if b.start() < a.start() => (Some(b), Some(a)),
+                (None, b) => (b, None),
+                pieces => pieces,
+            };
+        }
+        let (a, b) = (self.starts_at(), other.starts_at());
+        let (c, d) = (self.ends_at(), other.ends_at());
+        // only `dec`/`inc` if the bounds differ, so neither can overflow
+        (
+            (a != b).then(|| a.clone().min(b.clone())..=a.max(b).dec()),
+            (c != d).then(|| c.clone().min(d.clone()).inc()..=c.max(d)),
+        )
+    }
+    /// The elements strictly between two disjoint ranges, e.g. `0..=3` and `7..=9` is `4..=6`
+    ///
+    /// `None` if the ranges intersect, are adjacent or if either is empty.
+    fn gap(&self, other: &impl RangeUtil<T>) -> Option<RangeInclusive<T>> {
+        if self.is_empty() || other.is_empty() || self.intersects(other) || self.is_adjacent(other)
+        {
+            return None;
+        }
+        let (lower_end, upper_start) = if self.ends_at() < other.starts_at() {
+            (self.ends_at(), other.starts_at())
+        } else {
+            (other.ends_at(), self.starts_at())
+        };
+        // there is at least one element between both ranges, so neither overflows
+        Some(lower_end.inc()..=upper_start.dec())
+    }
+    /// The number of elements strictly between both ranges, e.g. `(0..=3).range_distance(&(7..=9)) == 3`
+    ///
+    /// This is the `len` of the `gap`, so `T::ZERO` if the ranges intersect, are adjacent or if either is empty. A distance that doesn't fit into `T` saturates at `T::MAX_VALUE`, e.g. `(..=i8::MIN).range_distance(&(i8::MAX..)) == i8::MAX`; use `cardinality` of the `gap` for the exact count.
+    fn range_distance(&self, other: &impl RangeUtil<T>) -> T
+    where
+        T: BasicInt + Sub<Output = T>,
+    {
+        // `len` of a non-empty gap is only `None` if it doesn't fit into `T`
+        self.gap(other)
+            .map_or(T::ZERO, |gap| RangeUtil::len(&gap).unwrap_or(T::MAX_VALUE))
+    }
+}
+/// Each end is resolved from its own bound. An excluded start at `MAX_VALUE` or an excluded end at `MIN_VALUE` can't be resolved with `inc`/`dec` and saturates, e.g. `(..0u8).ends_at() == 0`; these ranges are empty, which `is_empty` and `to_inclusive` (`(..0u8).to_inclusive() == 1..=0`) take into account.
+impl<T: Ord + Clone + BasicNum, R: RangeBounds<T> + Clone> RangeUtil<T> for R {
+    fn starts_at(&self) -> T {
+        match self.start_bound() {
+            Bound::Excluded(x) => x.checked_inc().unwrap_or_else(|| x.clone()),
+            Bound::Included(x) => x.clone(),
+            Bound::Unbounded => T::MIN_VALUE,
+        }
+    }
+    fn ends_at(&self) -> T {
+        match self.end_bound() {
+            Bound::Excluded(x) => x.checked_dec().unwrap_or_else(|| x.clone()),
+            Bound::Included(x) => x.clone(),
+            Bound::Unbounded => T::MAX_VALUE,
+        }
+    }
+    fn is_empty(&self) -> bool {
+        self.to_inclusive().is_empty()
+    }
+    fn to_inclusive(&self) -> RangeInclusive<T> {
+        match (self.start_bound(), self.end_bound()) {
+            // moving the other end keeps the range empty
+            (Bound::Excluded(x), _) if x == &T::MAX_VALUE => {
+                T::MAX_VALUE..=self.ends_at().min(x.dec())
+            }
+            (_, Bound::Excluded(x)) if x == &T::MIN_VALUE => {
+                self.starts_at().max(x.inc())..=T::MIN_VALUE
+            }
+            _ => self.starts_at()..=self.ends_at(),
+        }
+    }
+    fn is_start_unbounded(&self) -> bool {
+        matches!(self.start_bound(), Bound::Unbounded)
+    }
+    fn is_end_unbounded(&self) -> bool {
+        matches!(self.end_bound(), Bound::Unbounded)
+    }
+}
+
+/// The range `center - radius..=center + radius`, e.g. `from_center(10, 3) == 7..=13`
+///
+/// This is the inverse of `RangeUtil::midpoint`, the ends saturate at `T::MIN_VALUE`/`T::MAX_VALUE` (see `RangeUtil::grow`).
+pub fn from_center<T>(center: T, radius: T) -> RangeInclusive<T>
+where
+    T: Ord + Clone + BasicInt + Add<Output = T> + Sub<Output = T>,
+{
+    (center.clone()..=center).grow(radius)
+}
+
+/// Merges intersecting and adjacent ranges into the minimal set of disjoint ranges, sorted by start, e.g. `[5..=6, 0..=3, 2..=4]` is `[0..=6]`
+///
+/// Empty ranges are dropped.
+#[cfg(feature = "alloc")]
+pub fn coalesce<T: Ord + Clone + BasicNum>(ranges: &[RangeInclusive<T>]) -> Vec<RangeInclusive<T>> {
+    let mut sorted = ranges
+        .iter()
+        .filter(|r| !r.is_empty())
+        .cloned()
+        .collect::<Vec<_>>();
+    sort_ranges(&mut sorted);
+    let mut merged: Vec<RangeInclusive<T>> = Vec::with_capacity(sorted.len());
+    for range in sorted {
+        match merged.last_mut() {
+            Some(last) if last.intersects(&range) || last.is_adjacent(&range) => {
+                if range.end() > last.end() {
+                    *last = last.start().clone()..=range.end().clone();
+                }
+            }
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+/// Same as `coalesce`, but for any iterator of ranges, e.g. `[0..=3, 5..=6, 2..=4]` is `[0..=6]`
+#[cfg(feature = "alloc")]
+pub fn union_all<T: Ord + Clone + BasicNum, R: RangeUtil<T>>(
+    ranges: impl IntoIterator<Item = R>,
+) -> Vec<RangeInclusive<T>> {
+    let ranges = ranges
+        .into_iter()
+        .map(|r| r.to_inclusive())
+        .collect::<Vec<_>>();
+    coalesce(&ranges)
+}
+
+/// The `ranges` intersecting `probe` in their original order, e.g. `[0..=3, 4..=6, 8..=9]` with probe `5..=8` is `[4..=6, 8..=9]`
+///
+/// This is a linear scan, adjacent ranges (without common elements) are not included.
+#[cfg(feature = "alloc")]
+pub fn retain_intersecting<T: Ord + Clone + BasicNum>(
+    ranges: &[RangeInclusive<T>],
+    probe: &impl RangeUtil<T>,
+) -> Vec<RangeInclusive<T>> {
+    ranges
+        .iter()
+        .filter(|r| r.intersects(probe))
+        .cloned()
+        .collect()
+}
+
+/// Sorts `ranges` by their start, then by their end, e.g. `[3..=4, 0..=10, 0..=2]` is sorted to `[0..=2, 0..=10, 3..=4]`
+pub fn sort_ranges<T: Ord + Clone + BasicNum>(ranges: &mut [RangeInclusive<T>]) {
+    ranges.sort_unstable_by(|a, b| a.cmp_by_start(b).then_with(|| a.cmp_by_end(b)));
+}
+
+/// Sorts `ranges` like `sort_ranges` and removes identical ranges, e.g. `[3..=4, 0..=2, 0..=2]` becomes `[0..=2, 3..=4]`
+///
+/// Unlike `coalesce`, intersecting and adjacent ranges are kept as they are.
+#[cfg(feature = "alloc")]
+pub fn dedup_ranges<T: Ord + Clone + BasicNum>(ranges: &mut Vec<RangeInclusive<T>>) {
+    sort_ranges(ranges);
+    ranges.dedup();
+}
+
+/// The smallest range containing all `ranges`, e.g. `[0..=2, 7..=9, 4..=5]` is `0..=9`
+///
+/// Empty ranges are ignored, `None` if there are no (non-empty) ranges.
+pub fn span_of<T: Ord + Clone + BasicNum, R: RangeUtil<T>>(
+    ranges: impl IntoIterator<Item = R>,
+) -> Option<RangeInclusive<T>> {
+    ranges
+        .into_iter()
+        .filter(|r| !r.is_empty())
+        .fold(None, |span: Option<RangeInclusive<T>>, r| {
+            Some(match span {
+                Some(span) => span.bounding(&r),
+                None => r.starts_at()..=r.ends_at(),
+            })
+        })
+}
+
+/// The elements contained in all `ranges`, e.g. `[0..=10, 5..=15, 8..=12]` is `8..=10`
+///
+/// Stops at the first range not intersecting the previous ones, `None` if there are no common elements or no ranges at all.
+pub fn intersect_all<T: Ord + Clone + BasicNum, R: RangeUtil<T>>(
+    ranges: impl IntoIterator<Item = R>,
+) -> Option<RangeInclusive<T>> {
+    let mut ranges = ranges.into_iter();
+    let first = ranges.next()?;
+    let first = first.normalize()?;
+    ranges.try_fold(first, |common, r| common.intersection(&r))
+}
+
+/// The range between `start` and `end` resolved like `starts_at`/`ends_at`, e.g. `try_from_bounds(Bound::Excluded(3), Bound::Unbounded) == Ok(4..=u8::MAX)`
+///
+/// Errors with `RangeError::Empty` if the bounds contain no elements, e.g. `(Bound::Excluded(3), Bound::Included(3))`.
+pub fn try_from_bounds<T: Ord + Clone + BasicNum>(
+    start: Bound<T>,
+    end: Bound<T>,
+) -> Result<RangeInclusive<T>, RangeError> {
+    let bounds = (start, end);
+    if RangeUtil::<T>::is_empty(&bounds) {
+        return Err(RangeError::Empty);
+    }
+    Ok(bounds.starts_at()..=bounds.ends_at())
+}
+
+/// Renders `range` as `start..=end`, e.g. `format_range(&(0..=5)) == "0..=5"`
+#[cfg(feature = "alloc")]
+pub fn format_range<T: core::fmt::Display>(range: &RangeInclusive<T>) -> String {
+    format!("{}..={}", range.start(), range.end())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cmp::{Ordering, Reverse},
+        num::{NonZeroI8, NonZeroU32, NonZeroU8},
+        ops::{Add, Bound, RangeFull},
+        vec,
+        vec::Vec,
+    };
+
+    #[cfg(feature = "alloc")]
+    use crate::{coalesce, dedup_ranges, format_range, retain_intersecting, union_all};
+    use crate::{
+        from_center, intersect_all, sort_ranges, span_of, try_from_bounds, BasicInt, BasicNum,
+        IntersectError, RangeError, RangeRelation, RangeUtil,
+    };
+
+    #[test]
+    fn test_intersection_range_inclusive() {
+        assert_eq!((0..=3).intersection(&(1..=2)), Some(1..=2));
+        assert_eq!((0..=3).intersection(&(1..=30)), Some(1..=3));
+        assert_eq!((0..=3).intersection(&(-10..1)), Some(0..=0));
+        assert_eq!((0..=3).intersection(&(-10..=1)), Some(0..=1));
+        assert_eq!((0..=3).intersection(&(-10..=-1)), None);
+        assert_eq!((0..=3).intersection(&(4..=10)), None);
+        assert_eq!((0..=10).intersection(&(5..5)), None);
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_checked_intersection() {
+        assert_eq!((0..=3).checked_intersection(&(2..=6)), Ok(2..=3));
+        assert_eq!((0..=3).checked_intersection(&(3..)), Ok(3..=3));
+        assert_eq!((0..=3).checked_intersection(&(..)), Ok(0..=3));
+        assert_eq!(
+            (0..=3).checked_intersection(&(4..=7)),
+            Err(IntersectError::Adjacent)
+        );
+        assert_eq!(
+            (4..=7).checked_intersection(&(0..4)),
+            Err(IntersectError::Adjacent)
+        );
+        assert_eq!(
+            (0..=3).checked_intersection(&(5..=7)),
+            Err(IntersectError::Disjoint)
+        );
+        assert_eq!(
+            (0..=3).checked_intersection(&(4..=3)),
+            Err(IntersectError::Disjoint)
+        );
+        assert_eq!(
+            (0..0).checked_intersection(&(0..0)),
+            Err(IntersectError::Disjoint)
+        );
+    }
+
+    #[test]
+    fn test_overlap_len() {
+        assert_eq!((0..=5).overlap_len(&(3..=10)), Some(3));
+        assert_eq!((3..=10).overlap_len(&(0..=5)), Some(3));
+        assert_eq!((0..=5).overlap_len(&(5..)), Some(1));
+        assert_eq!((0..=10).overlap_len(&(2..4)), Some(2));
+        assert_eq!((0..=5).overlap_len(&(6..=10)), None);
+        assert_eq!((250u8..).overlap_len(&(..)), Some(6));
+        assert_eq!((i8::MIN..=i8::MAX).overlap_len(&(..)), None);
+        assert_eq!((-100i8..=0).overlap_len(&(-50..)), Some(51));
+    }
+
+    #[test]
+    fn test_overlap_ratio() {
+        assert_eq!((0..=3).overlap_ratio(&(2..=5)), 2.0 / 6.0);
+        assert_eq!((0..=3).overlap_ratio(&(0..=7)), 0.5);
+        assert_eq!((0..=7).overlap_ratio(&(0..=3)), 0.5);
+        assert_eq!((0..=3).overlap_ratio(&(0..4)), 1.0);
+        assert_eq!((0..=3).overlap_ratio(&(4..=7)), 0.0);
+        assert_eq!((0..0).overlap_ratio(&(3..3)), 0.0);
+        assert_eq!((..=u64::MAX).overlap_ratio(&(..=u64::MAX / 2)), 0.5);
+    }
+
+    #[test]
+    fn test_clamp_range() {
+        assert_eq!((2..=5).clamp_range(&(0..=10)), Some(2..=5));
+        assert_eq!((5..=15).clamp_range(&(0..=10)), Some(5..=10));
+        assert_eq!((-5..=5).clamp_range(&(0..=10)), Some(0..=5));
+        assert_eq!((..).clamp_range(&(0..=10)), Some(0..=10));
+        assert_eq!((20..=30).clamp_range(&(0..=10)), None);
+        assert_eq!((11..).clamp_range(&(0..=10)), None);
+    }
+
+    #[test]
+    fn test_difference_range_inclusive() {
+        assert_eq!((0..=3).setminus(&(4..=100)), (Some(0..=3), None));
+        assert_eq!((0..=3).setminus(&(-100..=-1)), (Some(0..=3), None));
+        assert_eq!((0..=3).setminus(&(1..=2)), (Some(0..=0), Some(3..=3)));
+        assert_eq!((0..=3).setminus(&(0..=2)), (None, Some(3..=3)));
+        assert_eq!((0..=3).setminus(&(1..=3)), (Some(0..=0), None));
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_len() {
+        assert_eq!(RangeUtil::len(&(0..=0)), Some(1));
+        assert_eq!(RangeUtil::len(&(0..=3)), Some(4));
+        assert_eq!(RangeUtil::len(&(0..4)), Some(4));
+        assert_eq!(RangeUtil::len(&(-3..=3)), Some(7));
+        assert_eq!(RangeUtil::len(&(5u8..)), Some(251));
+        assert_eq!(RangeUtil::len(&(..=254u8)), Some(255));
+        assert_eq!(RangeUtil::len(&(0..0)), None);
+        assert_eq!(RangeUtil::len(&(3..=0)), None);
+        assert_eq!(RangeUtil::<i8>::len(&(-100..=100)), None);
+        assert_eq!(RangeUtil::<i8>::len(&(-64..=62)), Some(127));
+        assert_eq!(RangeUtil::<i8>::len(&(..)), None);
+        assert_eq!(RangeUtil::<u128>::len(&(..)), None);
+        assert_eq!(RangeUtil::<i128>::len(&(1..)), Some(i128::MAX));
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_cardinality() {
+        assert_eq!((0..=3).cardinality(), 4);
+        assert_eq!((-3..3).cardinality(), 6);
+        assert_eq!((3..=0).cardinality(), 0);
+        assert_eq!((u64::MIN..=u64::MAX).cardinality(), 2u128.pow(64));
+        assert_eq!((i64::MIN..=i64::MAX).cardinality(), 2u128.pow(64));
+        assert_eq!(RangeUtil::<i8>::cardinality(&RangeFull), 256);
+        assert_eq!((1u128..).cardinality(), u128::MAX);
+        assert_eq!(RangeUtil::<i128>::cardinality(&RangeFull), u128::MAX);
+        assert_eq!((i128::MIN..=-1).cardinality(), 2u128.pow(127));
+    }
+
+    #[test]
+    fn test_offset_from_min() {
+        assert_eq!(0u8.offset_from_min(), 0);
+        assert_eq!(255u8.offset_from_min(), 255);
+        assert_eq!(i8::MIN.offset_from_min(), 0);
+        assert_eq!(0i8.offset_from_min(), 128);
+        assert_eq!(u128::MAX.offset_from_min(), u128::MAX);
+        assert_eq!(i128::MAX.offset_from_min(), u128::MAX);
+
+        assert_eq!(u8::from_offset(255), 255);
+        assert_eq!(i8::from_offset(0), i8::MIN);
+        assert_eq!(i8::from_offset(128), 0);
+        assert_eq!(i128::from_offset(u128::MAX), i128::MAX);
+        assert_eq!(
+            char::from_offset('\u{D7FF}'.offset_from_min() + 1),
+            '\u{E000}'
+        );
+        for x in [i8::MIN, -1, 1, i8::MAX].map(|x| NonZeroI8::new(x).unwrap()) {
+            assert_eq!(NonZeroI8::from_offset(x.offset_from_min()), x);
+        }
+        assert_eq!(NonZeroU8::from_offset(0), NonZeroU8::MIN);
+    }
+
+    #[test]
+    fn test_midpoint() {
+        assert_eq!((0..=10).midpoint(), Some(5));
+        assert_eq!((0..=9).midpoint(), Some(4));
+        assert_eq!((-9..=0).midpoint(), Some(-5));
+        assert_eq!((3..=3).midpoint(), Some(3));
+        assert_eq!((3..3).midpoint(), None);
+        assert_eq!(RangeUtil::<u64>::midpoint(&RangeFull), Some(u64::MAX / 2));
+        assert_eq!(RangeUtil::<i128>::midpoint(&RangeFull), Some(-1));
+        assert_eq!((200u8..).midpoint(), Some(227));
+        assert_eq!(('a'..='c').midpoint(), Some('b'));
+    }
+
+    #[test]
+    fn test_nth() {
+        assert_eq!((10..=20).nth(3), Some(13));
+        assert_eq!((10..=20).nth(0), Some(10));
+        assert_eq!((10..=20).nth(10), Some(20));
+        assert_eq!((10..=20).nth(11), None);
+        assert_eq!((10..=20).nth(-1), None);
+        assert_eq!((10..10).nth(0), None);
+        assert_eq!((..).nth(255u8), Some(255));
+        assert_eq!((-128i8..).nth(127), Some(-1));
+        assert_eq!((0i8..).nth(127), Some(127));
+        assert_eq!((1i8..).nth(127), None);
+        assert_eq!(RangeUtil::nth(&(..0u8), 0), None);
+    }
+
+    #[test]
+    fn test_position_of() {
+        assert_eq!((10..=20).position_of(&13), Some(3));
+        assert_eq!((10..=20).position_of(&10), Some(0));
+        assert_eq!((10..=20).position_of(&20), Some(10));
+        assert_eq!((10..=20).position_of(&21), None);
+        assert_eq!((10..=20).position_of(&9), None);
+        assert_eq!((-5..).position_of(&5), Some(10));
+        assert_eq!((..).position_of(&255u8), Some(255));
+        assert_eq!((..).position_of(&-1i8), Some(127));
+        assert_eq!((..).position_of(&0i8), None);
+        assert_eq!((10..=20).nth((10..=20).position_of(&17).unwrap()), Some(17));
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_is_empty() {
+        assert!(!RangeUtil::is_empty(&(0..=3)));
+        assert!(!RangeUtil::is_empty(&(0..=0)));
+        assert!(RangeUtil::is_empty(&(0..0)));
+        assert!(RangeUtil::is_empty(&(3..=0)));
+        assert!(!RangeUtil::is_empty(&(3..)));
+        assert!(RangeUtil::is_empty(&(1u8..1)));
+        assert!(!RangeUtil::<u8>::is_empty(&RangeFull));
+        assert_eq!(
+            RangeUtil::is_empty(&(0..0)),
+            RangeUtil::len(&(0..0)).is_none()
+        );
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_clamp_value() {
+        assert_eq!((0..=10).clamp_value(-5), 0);
+        assert_eq!((0..=10).clamp_value(5), 5);
+        assert_eq!((0..=10).clamp_value(15), 10);
+        assert_eq!((0..10).clamp_value(15), 9);
+        assert_eq!((..=10u8).clamp_value(0), 0);
+        assert_eq!((5..=3).clamp_value(15), 15);
+
+        assert_eq!((0..=10).checked_clamp_value(15), Some(10));
+        assert_eq!((0..=10).checked_clamp_value(5), Some(5));
+        assert_eq!((5..=3).checked_clamp_value(15), None);
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_values() {
+        assert_eq!((0..=3).values().collect::<Vec<_>>(), [0, 1, 2, 3]);
+        assert_eq!((0..4).values().collect::<Vec<_>>(), [0, 1, 2, 3]);
+        assert_eq!((5..=5).values().collect::<Vec<_>>(), [5]);
+        assert_eq!((5..5).values().count(), 0);
+        assert_eq!((5..=3).values().count(), 0);
+        assert_eq!((254u8..).values().collect::<Vec<_>>(), [254, 255]);
+        assert_eq!((..=i8::MIN).values().collect::<Vec<_>>(), [i8::MIN]);
+        assert_eq!(RangeUtil::<u8>::values(&RangeFull).count(), 256);
+        assert_eq!((..0u8).values().count(), 0);
+        assert_eq!(
+            (Bound::Excluded(u8::MAX), Bound::Unbounded).values().next(),
+            None
+        );
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_values_rev() {
+        assert_eq!((0..=3).values_rev().collect::<Vec<_>>(), [3, 2, 1, 0]);
+        assert_eq!((5..=5).values_rev().collect::<Vec<_>>(), [5]);
+        assert_eq!((5..=3).values_rev().count(), 0);
+        assert_eq!((..=1u8).values_rev().collect::<Vec<_>>(), [1, 0]);
+        assert_eq!((i8::MAX..).values_rev().collect::<Vec<_>>(), [i8::MAX]);
+
+        let mut values = (0..=4).values();
+        assert_eq!(values.next(), Some(0));
+        assert_eq!(values.next_back(), Some(4));
+        assert_eq!(values.next_back(), Some(3));
+        assert_eq!(values.next(), Some(1));
+        assert_eq!(values.next(), Some(2));
+        assert_eq!(values.next_back(), None);
+        assert_eq!(values.next(), None);
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_step_values() {
+        assert_eq!((0..=10).step_values(3).collect::<Vec<_>>(), [0, 3, 6, 9]);
+        assert_eq!((0..=9).step_values(3).collect::<Vec<_>>(), [0, 3, 6, 9]);
+        assert_eq!((0..=3).step_values(1).collect::<Vec<_>>(), [0, 1, 2, 3]);
+        assert_eq!((0..=3).step_values(10).collect::<Vec<_>>(), [0]);
+        assert_eq!((-5..=5).step_values(5).collect::<Vec<_>>(), [-5, 0, 5]);
+        assert_eq!((5..=3).step_values(1).count(), 0);
+        assert_eq!(
+            (250u8..).step_values(2).collect::<Vec<_>>(),
+            [250, 252, 254]
+        );
+        assert_eq!(
+            (..).step_values(i8::MAX).collect::<Vec<_>>(),
+            [-128, -1, 126]
+        );
+        assert_eq!((..0u8).step_values(1).count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "step must be positive")]
+    fn test_step_values_zero() {
+        (0..=10).step_values(0);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_split_on() {
+        assert_eq!((0..=10).split_on(&[3, 7]), [0..=2, 3..=6, 7..=10]);
+        assert_eq!((0..=10).split_on(&[]), [0..=10]);
+        // points on the boundaries
+        assert_eq!((0..=10).split_on(&[0, 10]), [0..=9, 10..=10]);
+        assert_eq!((0..=10).split_on(&[0, 1, 2]), [0..=0, 1..=1, 2..=10]);
+        // points outside of the range and duplicates
+        assert_eq!(
+            (0..=10).split_on(&[-5, 3, 3, 7, 11, 20]),
+            [0..=2, 3..=6, 7..=10]
+        );
+        assert_eq!((0..=10).split_on(&[-5, 20]), [0..=10]);
+        assert!((5..5).split_on(&[5]).is_empty());
+        assert_eq!(
+            (250u8..).split_on(&[252, 255]),
+            [250..=251, 252..=254, 255..=255]
+        );
+    }
+
+    #[test]
+    fn test_split_at() {
+        assert_eq!((0..=9).split_at(5), (Some(0..=4), Some(5..=9)));
+        assert_eq!((0..=9).split_at(0), (None, Some(0..=9)));
+        assert_eq!((0..=9).split_at(9), (Some(0..=8), Some(9..=9)));
+        assert_eq!((0..=9).split_at(10), (Some(0..=9), None));
+        assert_eq!((0..=9).split_at(-5), (None, Some(0..=9)));
+        assert_eq!((0..=9).split_at(50), (Some(0..=9), None));
+        assert_eq!((5..5).split_at(5), (None, None));
+        assert_eq!((..).split_at(u8::MIN), (None, Some(0..=255)));
+        assert_eq!((..).split_at(u8::MAX), (Some(0..=254), Some(255..=255)));
+        assert_eq!((..0u8).split_at(0), (None, None));
+        assert_eq!((..0u8).split_at(1), (None, None));
+    }
+
+    #[test]
+    fn test_chunks() {
+        assert_eq!((0..=9).chunks(4).collect::<Vec<_>>(), [0..=3, 4..=7, 8..=9]);
+        assert_eq!((0..=7).chunks(4).collect::<Vec<_>>(), [0..=3, 4..=7]);
+        assert_eq!((0..=2).chunks(1).collect::<Vec<_>>(), [0..=0, 1..=1, 2..=2]);
+        assert_eq!((0..=2).chunks(10).collect::<Vec<_>>(), [0..=2]);
+        assert_eq!((5..5).chunks(10).count(), 0);
+        assert_eq!(
+            (250u8..).chunks(4).collect::<Vec<_>>(),
+            [250..=253, 254..=255]
+        );
+        assert_eq!(
+            (..).chunks(i8::MAX).collect::<Vec<_>>(),
+            [-128..=-2, -1..=125, 126..=127]
+        );
+        assert_eq!((..0u8).chunks(1).next(), None);
+        assert!((0..=24).tile(10).eq((0..=24).chunks(10)));
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be positive")]
+    fn test_chunks_zero() {
+        (0..=10).chunks(0);
+    }
+
+    #[test]
+    fn test_windows() {
+        assert_eq!(
+            (0..=4).windows(3).collect::<Vec<_>>(),
+            [0..=2, 1..=3, 2..=4]
+        );
+        assert_eq!((0..=4).windows(3).count(), 3);
+        assert_eq!((0..=99).windows(10).count(), 91);
+        assert_eq!((0..=99).windows(10).last(), Some(90..=99));
+        assert_eq!(
+            (0..=4).windows(1).collect::<Vec<_>>(),
+            [0..=0, 1..=1, 2..=2, 3..=3, 4..=4]
+        );
+        assert_eq!((0..=4).windows(5).collect::<Vec<_>>(), [0..=4]);
+        assert_eq!((0..=4).windows(6).count(), 0);
+        assert_eq!((5..5).windows(1).count(), 0);
+        assert_eq!(
+            (252u8..).windows(3).collect::<Vec<_>>(),
+            [252..=254, 253..=255]
+        );
+        assert_eq!(
+            (..=-126i8).windows(2).collect::<Vec<_>>(),
+            [-128..=-127, -127..=-126]
+        );
+        assert_eq!((..=-126i8).windows(4).count(), 0);
+        assert_eq!((..=-126i8).windows(i8::MAX).count(), 0);
+        assert_eq!((..0u8).windows(1).next(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be positive")]
+    fn test_windows_zero() {
+        (0..=10).windows(0);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_split_into() {
+        assert_eq!((0..=9).split_into(3), [0..=3, 4..=6, 7..=9]);
+        assert_eq!((0..=9).split_into(4), [0..=2, 3..=5, 6..=7, 8..=9]);
+        assert_eq!((0..=9).split_into(5), [0..=1, 2..=3, 4..=5, 6..=7, 8..=9]);
+        assert_eq!((0..=9).split_into(1), [0..=9]);
+        assert_eq!((0..=9).split_into(0), []);
+        assert_eq!((0..=2).split_into(5), [0..=0, 1..=1, 2..=2]);
+        assert_eq!((5..5).split_into(3), []);
+        assert_eq!((..).split_into(2), [-128..=-1, 0i8..=127]);
+        assert_eq!((..).split_into(3), [0..=85, 86..=170, 171u8..=255]);
+        assert_eq!(
+            (..).split_into(2),
+            [0..=u128::MAX / 2, u128::MAX / 2 + 1..=u128::MAX]
+        );
+    }
+
+    #[test]
+    fn test_complement() {
+        assert_eq!((2..=4).complement(&(0..=9)), (Some(0..=1), Some(5..=9)));
+        assert_eq!((0..=4).complement(&(0..=9)), (None, Some(5..=9)));
+        assert_eq!((-5..=4).complement(&(0..=9)), (None, Some(5..=9)));
+        assert_eq!((5..).complement(&(0..=9)), (Some(0..=4), None));
+        assert_eq!((..).complement(&(0..=9)), (None, None));
+        assert_eq!((0..10).complement(&(0..=9)), (None, None));
+        assert_eq!((20..=30).complement(&(0..=9)), (Some(0..=9), None));
+        assert_eq!((20..=30).complement(&(5..5)), (None, None));
+        assert_eq!((10u8..).complement(&(..)), (Some(0..=9), None));
+    }
+
+    #[test]
+    fn test_cmp_by_start_end() {
+        assert_eq!((0..=10).cmp_by_start(&(3..=4)), Ordering::Less);
+        assert_eq!((0..=10).cmp_by_start(&(0..3)), Ordering::Equal);
+        assert_eq!((4..).cmp_by_start(&(3..=4)), Ordering::Greater);
+        assert_eq!((0..=10).cmp_by_end(&(3..=4)), Ordering::Greater);
+        assert_eq!((0..=4).cmp_by_end(&(3..5)), Ordering::Equal);
+        assert_eq!((..=4u8).cmp_by_end(&(3..)), Ordering::Less);
+    }
+
+    #[test]
+    fn test_sort_ranges() {
+        let mut ranges = [3..=4, 0..=10, 0..=2];
+        sort_ranges(&mut ranges);
+        assert_eq!(ranges, [0..=2, 0..=10, 3..=4]);
+        let mut ranges = vec![5..=5, -3..=8, 5..=9, -3..=-3];
+        sort_ranges(&mut ranges);
+        assert_eq!(ranges, [-3..=-3, -3..=8, 5..=5, 5..=9]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_dedup_ranges() {
+        let mut ranges = vec![0..=2, 0..=2, 3..=4];
+        dedup_ranges(&mut ranges);
+        assert_eq!(ranges, [0..=2, 3..=4]);
+        let mut ranges = vec![3..=4, 0..=2, 3..=4, 0..=2, 0..=2];
+        dedup_ranges(&mut ranges);
+        assert_eq!(ranges, [0..=2, 3..=4]);
+        // only identical ranges are removed
+        let mut ranges = vec![0..=5, 2..=3, 0..=2, 2..=3];
+        dedup_ranges(&mut ranges);
+        assert_eq!(ranges, [0..=2, 0..=5, 2..=3]);
+        let mut ranges: Vec<std::ops::RangeInclusive<i32>> = Vec::new();
+        dedup_ranges(&mut ranges);
+        assert!(ranges.is_empty());
+    }
+
+    #[test]
+    fn test_shift() {
+        assert_eq!((2..=4).shift(3), 5..=7);
+        assert_eq!((2..=4).shift(-3), -1..=1);
+        assert_eq!((2..5).shift(0), 2..=4);
+        assert_eq!((250u8..=255).shift(10), 255..=255);
+        assert_eq!((0u8..=5).shift(10), 10..=15);
+        assert_eq!((-128i8..=-120).shift(-10), -128..=-128);
+        assert_eq!((100i8..).shift(-100), 0..=27);
+        assert_eq!(
+            RangeUtil::len(&(10..=20).shift(7)),
+            RangeUtil::len(&(10..=20))
+        );
+
+        assert_eq!((250u8..=255).checked_shift(10), None);
+        assert_eq!((240u8..=245).checked_shift(10), Some(250..=255));
+        assert_eq!((-128i8..=-120).checked_shift(-1), None);
+        assert_eq!((-128i8..=-120).checked_shift(1), Some(-127..=-119));
+        assert_eq!((..=5i8).checked_shift(i8::MAX), None);
+        assert!((..0u8).shift(3).is_empty());
+        assert!((..0u8).checked_shift(3).is_some_and(|r| r.is_empty()));
+    }
+
+    #[test]
+    fn test_grow_shrink() {
+        assert_eq!((5..=10).grow(2), 3..=12);
+        assert_eq!((5..=10).grow(0), 5..=10);
+        assert_eq!((1u8..=10).grow(2), 0..=12);
+        assert_eq!((250u8..).grow(200), 50..=255);
+        assert_eq!((-120i8..=120).grow(10), -128..=127);
+
+        assert_eq!((5..=10).shrink(2), Some(7..=8));
+        assert_eq!((5..=10).shrink(0), Some(5..=10));
+        assert_eq!((5..=10).shrink(3), None);
+        assert_eq!((5..=11).shrink(3), Some(8..=8));
+        assert_eq!((250u8..).shrink(100), None);
+        assert_eq!((..).shrink(127i8), Some(-1..=0));
+        assert_eq!((..).shrink(i8::MAX).and_then(|r| r.shrink(1)), None);
+        assert!((..0u8).grow(0).is_empty());
+        assert_eq!((..0u8).shrink(0), None);
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_contains_range() {
+        assert!((0..=10).contains_range(&(2..=5)));
+        assert!((0..=10).contains_range(&(0..=10)));
+        assert!((0..=10).contains_range(&(0..11)));
+        assert!(!(0..=10).contains_range(&(5..=15)));
+        assert!(!(0..=10).contains_range(&(-1..=5)));
+        assert!(!(0..=10).contains_range(&(20..=30)));
+        assert!((..).contains_range(&(3u8..)));
+        assert!((0..=10).contains_range(&(20..20)));
+        assert!((0..0).contains_range(&(5..=3)));
+        assert!(!(..0u8).contains_range(&(0..=0)));
+        assert!((..0u8).contains_range(&(5..5)));
+    }
+
+    #[test]
+    fn test_subset_superset() {
+        assert!((2..=5).is_subset_of(&(0..=10)));
+        assert!((2..=5).is_proper_subset_of(&(0..=10)));
+        assert!((0..=10).is_superset_of(&(2..=5)));
+        assert!((0..=10).is_proper_superset_of(&(2..=5)));
+
+        assert!((0..=3).is_subset_of(&(0..=3)));
+        assert!((0..=3).is_superset_of(&(0..4)));
+        assert!(!(0..=3).is_proper_subset_of(&(0..=3)));
+        assert!(!(0..=3).is_proper_superset_of(&(0..4)));
+
+        assert!(!(0..=3).is_subset_of(&(5..=8)));
+        assert!(!(0..=3).is_superset_of(&(5..=8)));
+        assert!(!(0..=3).is_proper_subset_of(&(5..=8)));
+        assert!(!(0..=3).is_proper_superset_of(&(5..=8)));
+
+        assert!((0..0).is_proper_subset_of(&(0..=3)));
+        assert!(!(0..0).is_proper_subset_of(&(5..5)));
+    }
+
+    #[test]
+    fn test_is_disjoint() {
+        assert!((0..=3).is_disjoint(&(4..=7)));
+        assert!((0..=3).is_disjoint(&(10..)));
+        assert!(!(0..=3).is_disjoint(&(3..=7)));
+        assert!(!(0..=3).is_disjoint(&(..)));
+        assert!((0..=3).is_disjoint(&(2..2)));
+    }
+
+    #[test]
+    fn test_is_adjacent() {
+        assert!((0..=3).is_adjacent(&(4..=7)));
+        assert!((4..=7).is_adjacent(&(0..=3)));
+        assert!((0..4).is_adjacent(&(4..)));
+        assert!(!(0..=3).is_adjacent(&(3..=7)));
+        assert!(!(0..=3).is_adjacent(&(5..=7)));
+        assert!(!(0..=3).is_adjacent(&(4..4)));
+        assert!(!(250u8..).is_adjacent(&(0..=10)));
+        assert!((250u8..).is_adjacent(&(..250)));
+        assert!((..=i8::MIN).is_adjacent(&(-127..)));
+        assert!(!(..0u8).is_adjacent(&(1..=3)));
+        assert!(!(250u8..=254).is_adjacent(&(Bound::Excluded(u8::MAX), Bound::Unbounded)));
+    }
+
+    #[test]
+    fn test_union_range_inclusive() {
+        assert_eq!((0..=3).union(&(2..=6)), (Some(0..=6), None));
+        assert_eq!((0..=3).union(&(4..=6)), (Some(0..=6), None));
+        assert_eq!((4..=6).union(&(0..=3)), (Some(0..=6), None));
+        assert_eq!((0..=3).union(&(5..=6)), (Some(0..=3), Some(5..=6)));
+        assert_eq!((5..=6).union(&(0..=3)), (Some(0..=3), Some(5..=6)));
+        assert_eq!((0..=10).union(&(2..=3)), (Some(0..=10), None));
+        assert_eq!((0..=3).union(&(4..4)), (Some(0..=3), None));
+        assert_eq!((250u8..).union(&(..10)), (Some(0..=9), Some(250..=255)));
+        // `..0u8` is empty although both ends resolve to `0`
+        assert_eq!((..0u8).union(&(5..=6)), (Some(5..=6), None));
+    }
+
+    #[test]
+    fn test_bounding() {
+        assert_eq!((0..=2).bounding(&(7..=9)), 0..=9);
+        assert_eq!((7..=9).bounding(&(0..=2)), 0..=9);
+        assert_eq!((0..=5).bounding(&(3..=9)), 0..=9);
+        assert_eq!((0..=10).bounding(&(3..=4)), 0..=10);
+        assert_eq!((0..=2).bounding(&(20..20)), 0..=2);
+        assert_eq!((20..20).bounding(&(0..=2)), 0..=2);
+        assert_eq!((..3u8).bounding(&(250..)), 0..=255);
+        assert!((..0u8).bounding(&(5u8..5)).is_empty());
+        assert!((5u8..5).bounding(&(..0u8)).is_empty());
+    }
+
+    #[test]
+    fn test_expand_to_include() {
+        assert_eq!((3..=7).expand_to_include(10), 3..=10);
+        assert_eq!((3..=7).expand_to_include(1), 1..=7);
+        assert_eq!((3..=7).expand_to_include(5), 3..=7);
+        assert_eq!((3..=7).expand_to_include(7), 3..=7);
+        assert_eq!((3..3).expand_to_include(10), 10..=10);
+        assert_eq!(
+            [4, 9, -2, 6]
+                .into_iter()
+                .fold(0..=0, |r, x| r.expand_to_include(x)),
+            -2..=9
+        );
+    }
+
+    #[test]
+    fn test_symmetric_difference() {
+        assert_eq!(
+            (0..=3).symmetric_difference(&(2..=5)),
+            (Some(0..=1), Some(4..=5))
+        );
+        assert_eq!(
+            (2..=5).symmetric_difference(&(0..=3)),
+            (Some(0..=1), Some(4..=5))
+        );
+        assert_eq!(
+            (0..=10).symmetric_difference(&(3..=5)),
+            (Some(0..=2), Some(6..=10))
+        );
+        assert_eq!((0..=3).symmetric_difference(&(0..=5)), (None, Some(4..=5)));
+        assert_eq!((0..=5).symmetric_difference(&(2..=5)), (Some(0..=1), None));
+        assert_eq!((0..=3).symmetric_difference(&(0..4)), (None, None));
+        assert_eq!(
+            (7..=9).symmetric_difference(&(0..=3)),
+            (Some(0..=3), Some(7..=9))
+        );
+        assert_eq!(
+            (0..=3).symmetric_difference(&(4..=6)),
+            (Some(0..=3), Some(4..=6))
+        );
+        assert_eq!(
+            (4..=6).symmetric_difference(&(0..4)),
+            (Some(0..=3), Some(4..=6))
+        );
+        assert_eq!((0..=10).symmetric_difference(&(5..5)), (Some(0..=10), None));
+        assert_eq!((5..5).symmetric_difference(&(0..=10)), (Some(0..=10), None));
+        assert_eq!((5..5).symmetric_difference(&(3..3)), (None, None));
+        assert_eq!(
+            (..=10u8).symmetric_difference(&(5..)),
+            (Some(0..=4), Some(11..=255))
+        );
+    }
+
+    #[test]
+    fn test_gap() {
+        assert_eq!((0..=3).gap(&(7..=9)), Some(4..=6));
+        assert_eq!((7..=9).gap(&(0..=3)), Some(4..=6));
+        assert_eq!((0..=3).gap(&(5..=9)), Some(4..=4));
+        assert_eq!((0..=3).gap(&(4..=9)), None);
+        assert_eq!((0..=3).gap(&(2..=9)), None);
+        assert_eq!((0..=3).gap(&(8..8)), None);
+        assert_eq!((..=0u8).gap(&(255..)), Some(1..=254));
+        assert_eq!((250u8..).gap(&(..10)), Some(10..=249));
+    }
+
+    #[test]
+    fn test_from_center() {
+        assert_eq!(from_center(10, 3), 7..=13);
+        assert_eq!(from_center(10, 0), 10..=10);
+        assert_eq!(from_center(-10, 3), -13..=-7);
+        assert_eq!(from_center(250u8, 10), 240..=255);
+        assert_eq!(from_center(5u8, 10), 0..=15);
+        assert_eq!(from_center(10, 3).midpoint(), Some(10));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_coalesce() {
+        assert_eq!(coalesce::<i32>(&[]), []);
+        assert_eq!(coalesce(&[5..=6, 0..=3, 2..=4]), [0..=6]);
+        assert_eq!(coalesce(&[0..=3, 5..=6]), [0..=3, 5..=6]);
+        assert_eq!(coalesce(&[0..=3, 4..=6]), [0..=6]);
+        assert_eq!(coalesce(&[2..=3, 2..=3, 0..=0]), [0..=0, 2..=3]);
+        assert_eq!(coalesce(&[0..=10, 2..=3, 5..=7]), [0..=10]);
+        assert_eq!(coalesce(&[10..=12, 3..=1, 0..=2]), [0..=2, 10..=12]);
+        assert_eq!(coalesce(&[250u8..=255, 0..=5, 6..=249]), [0..=255]);
+    }
+
+    #[test]
+    fn test_char() {
+        assert_eq!('a'.inc(), 'b');
+        assert_eq!('b'.dec(), 'a');
+        assert_eq!('\u{D7FF}'.inc(), '\u{E000}');
+        assert_eq!('\u{E000}'.dec(), '\u{D7FF}');
+        assert_eq!(('a'..='z').intersection(&('x'..)), Some('x'..='z'));
+        assert_eq!(('a'..='m').intersection(&('n'..='z')), None);
+        assert!(('a'..='z').includes(&'q'));
+        assert!(!('a'..='z').includes(&'A'));
+        assert!(('a'..'y').is_adjacent(&('y'..)));
+        assert_eq!(('a'..='z').cardinality(), 26);
+        assert_eq!(RangeUtil::<char>::cardinality(&RangeFull), 0x110000 - 0x800);
+        assert_eq!(
+            ('\u{D700}'..'\u{E000}').setminus(&('\u{D000}'..'\u{D7FF}')),
+            (None, Some('\u{D7FF}'..='\u{D7FF}'))
+        );
+    }
+
+    #[test]
+    fn test_non_zero() {
+        let n = |x| NonZeroU32::new(x).unwrap();
+        let i = |x| NonZeroI8::new(x).unwrap();
+        assert_eq!(NonZeroU32::MIN_VALUE, n(1));
+        assert_eq!(n(1).inc(), n(2));
+        assert_eq!(n(2).dec(), n(1));
+        assert_eq!(i(-1).inc(), i(1));
+        assert_eq!(i(1).dec(), i(-1));
+        assert_eq!((n(1)..=n(10)).intersection(&(n(5)..)), Some(n(5)..=n(10)));
+        assert_eq!((n(1)..n(5)).intersection(&(n(5)..)), None);
+        assert_eq!((..n(5)).intersection(&(..=n(2))), Some(n(1)..=n(2)));
+        assert_eq!((i(-3)..=i(3)).cardinality(), 6);
+        assert_eq!(RangeUtil::<NonZeroU8>::cardinality(&RangeFull), 255);
+        assert_eq!(RangeUtil::<NonZeroI8>::cardinality(&RangeFull), 255);
+    }
+
+    #[test]
+    fn test_checked_inc_dec() {
+        assert_eq!(0u8.checked_dec(), None);
+        assert_eq!(0u8.checked_inc(), Some(1));
+        assert_eq!(u8::MAX.checked_inc(), None);
+        assert_eq!(i32::MIN.checked_dec(), None);
+        assert_eq!(i32::MIN.checked_inc(), Some(i32::MIN + 1));
+        assert_eq!(char::MAX.checked_inc(), None);
+        assert_eq!('\u{D7FF}'.checked_inc(), Some('\u{E000}'));
+        assert_eq!(NonZeroU8::MIN.checked_dec(), None);
+    }
+
+    #[test]
+    fn test_zero_one() {
+        assert_eq!(u8::ZERO, 0);
+        assert_eq!(u8::ONE, 1);
+        assert_eq!(i8::ZERO, 0);
+        assert_eq!(i8::ONE, 1);
+        assert_eq!(usize::ONE, 1);
+        assert_eq!(isize::ZERO, 0);
+        assert_eq!(u128::ONE, 1);
+        assert_eq!(i128::ZERO, 0);
+        fn steps<T: BasicInt + Add<Output = T>>(x: T) -> T {
+            x + T::ONE + T::ONE + T::ZERO
+        }
+        assert_eq!(steps(5u16), 7);
+        assert_eq!(steps(-5i64), -3);
+        assert_eq!(i32::ONE, 0i32.inc());
+    }
+
+    #[test]
+    #[cfg(feature = "derive")]
+    fn test_derive_basic_num() {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, crate::BasicNum)]
+        struct Tick(u64);
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, crate::BasicNum)]
+        struct Offset {
+            value: i8,
+        }
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, crate::BasicNum)]
+        struct Id<T>(T);
+
+        assert_eq!(Tick::MIN_VALUE, Tick(0));
+        assert_eq!(Tick::MAX_VALUE, Tick(u64::MAX));
+        assert_eq!(Tick(5).inc(), Tick(6));
+        assert_eq!(Tick(5).dec(), Tick(4));
+        assert_eq!(Tick(u64::MAX).checked_inc(), None);
+        assert_eq!(
+            (Tick(0)..=Tick(10)).intersection(&(Tick(5)..Tick(20))),
+            Some(Tick(5)..=Tick(10))
+        );
+        assert_eq!((Tick(0)..Tick(10)).cardinality(), 10);
+        assert_eq!(RangeUtil::<Offset>::cardinality(&RangeFull), 256);
+        assert_eq!(
+            (..Offset { value: -126 }).to_inclusive(),
+            Offset { value: -128 }..=Offset { value: -127 }
+        );
+        assert_eq!(
+            (Id('a')..=Id('z')).intersection(&(Id('x')..)),
+            Some(Id('x')..=Id('z'))
+        );
+
+        // `checked_*`/`saturating_*` are forwarded as well
+        assert_eq!(Tick(3).checked_dec(), Some(Tick(2)));
+        assert_eq!(Tick(0).saturating_dec(), Tick(0));
+        assert_eq!(Tick(u64::MAX).saturating_inc(), Tick(u64::MAX));
+        assert_eq!(Offset { value: i8::MIN }.checked_dec(), None);
+        assert_eq!(Id('\u{d7ff}').checked_inc(), Some(Id('\u{e000}')));
+        assert_eq!(Id(char::MAX).checked_inc(), None);
+    }
+
+    #[test]
+    fn test_saturating_inc_dec() {
+        assert_eq!(255u8.saturating_inc(), 255);
+        assert_eq!(254u8.saturating_inc(), 255);
+        assert_eq!(0u8.saturating_dec(), 0);
+        assert_eq!(1u8.saturating_dec(), 0);
+        assert_eq!(i64::MAX.saturating_inc(), i64::MAX);
+        assert_eq!(i64::MIN.saturating_dec(), i64::MIN);
+        assert_eq!(0i64.saturating_dec(), -1);
+        assert_eq!(char::MAX.saturating_inc(), char::MAX);
+        assert_eq!('\0'.saturating_dec(), '\0');
+        assert_eq!(NonZeroU8::MIN.saturating_dec(), NonZeroU8::MIN);
+        assert_eq!(NonZeroU8::MAX.saturating_inc(), NonZeroU8::MAX);
+    }
+
+    #[test]
+    fn test_boundary_overflow() {
+        assert_eq!((..=i32::MIN).setminus(&(..)), (None, None));
+        assert_eq!((i32::MAX..).setminus(&(..)), (None, None));
+        assert_eq!((..=i32::MIN).setminus(&(..0)), (None, None));
+        assert_eq!((..10u8).setminus(&(..5)), (None, Some(5..=9)));
+        assert_eq!((250u8..).setminus(&(252..)), (Some(250..=251), None));
+
+        assert!(RangeUtil::is_empty(&(0u8..0)));
+        assert!(RangeUtil::is_empty(&(..i8::MIN)));
+        let excluded_max = (Bound::Excluded(u8::MAX), Bound::Unbounded);
+        assert!(RangeUtil::is_empty(&excluded_max));
+        assert_eq!((0u8..0).cardinality(), 0);
+        assert_eq!(RangeUtil::len(&(0u8..0)), None);
+        assert_eq!(RangeUtil::<u8>::len(&RangeFull), None);
+        assert_eq!(RangeUtil::len(&(1u8..)), Some(255));
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_unresolvable_excluded_bounds() {
+        // each end is resolved from its own bound
+        assert_eq!((7u8..0).starts_at(), 7);
+        assert_eq!((7u8..0).ends_at(), 0);
+        assert_eq!((..0u8).starts_at(), 0);
+        assert_eq!((..0u8).ends_at(), 0);
+        let excluded_max = (Bound::Excluded(u8::MAX), Bound::Included(3));
+        assert_eq!(excluded_max.starts_at(), 255);
+        assert_eq!(excluded_max.ends_at(), 3);
+
+        // `to_inclusive` keeps the ranges empty
+        assert_eq!((..0u8).to_inclusive(), 1..=0);
+        assert_eq!((0u8..0).to_inclusive(), 1..=0);
+        assert_eq!((..i8::MIN).to_inclusive(), -127..=-128);
+        let excluded_max = (Bound::Excluded(u8::MAX), Bound::Unbounded);
+        assert_eq!(excluded_max.to_inclusive(), 255..=254);
+        let both = (Bound::Excluded(u8::MAX), Bound::Excluded(0));
+        assert_eq!(both.to_inclusive(), 255..=0);
+        assert!(RangeUtil::is_empty(&both));
+        assert_eq!(RangeUtil::len(&(..0u8)), None);
+        assert_eq!((..0u8).cardinality(), 0);
+        assert_eq!((..0u8).normalize(), None);
+
+        assert!(!(..0u8).includes(&0));
+        assert!(!excluded_max.includes(&255));
+        assert_eq!((..0u8).setminus(&(5..=6)), (None, None));
+        assert_eq!(excluded_max.setminus(&(0..=0)), (None, None));
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_span_of() {
+        assert_eq!(span_of([0..=2, 7..=9, 4..=5]), Some(0..=9));
+        assert_eq!(span_of([3..=6, 0..=4]), Some(0..=6));
+        assert_eq!(span_of([3..=6]), Some(3..=6));
+        assert_eq!(span_of([3..=6, 20..=0]), Some(3..=6));
+        assert_eq!(span_of([5..5, 20..20]), None);
+        assert_eq!(span_of(Vec::<std::ops::Range<i32>>::new()), None);
+        assert_eq!(span_of([10u8.., 3..]), Some(3..=255));
+    }
+
+    #[test]
+    fn test_intersect_all() {
+        assert_eq!(intersect_all([0..=10, 5..=15, 8..=12]), Some(8..=10));
+        assert_eq!(intersect_all([0..=10]), Some(0..=10));
+        assert_eq!(intersect_all([0..=10, 5..=15, 11..=12]), None);
+        assert_eq!(intersect_all([0..=3, 5..=8, 0..=8]), None);
+        assert_eq!(intersect_all([5..5, 0..10]), None);
+        assert_eq!(intersect_all(Vec::<std::ops::Range<i32>>::new()), None);
+        assert_eq!(intersect_all([..10u8, ..20]), Some(0..=9));
+        assert_eq!(intersect_all([..0u8]), None);
+    }
+
+    #[test]
+    fn test_from_incl() {
+        assert_eq!((0..).starts_at(), 0);
+        assert_eq!((0..1).starts_at(), 0);
+        assert_eq!((0..=1).starts_at(), 0);
+        assert_eq!((..=10).starts_at(), 0usize);
+        assert_eq!((..=10).starts_at(), isize::MIN);
+        assert_eq!(RangeUtil::<usize>::starts_at(&RangeFull), 0);
+        assert_eq!(RangeUtil::<isize>::starts_at(&RangeFull), isize::MIN);
     }
 
     #[test]
@@ -147,4 +2159,623 @@ mod tests {
         assert_eq!(RangeUtil::<usize>::ends_at(&RangeFull), usize::MAX);
         assert_eq!(RangeUtil::<isize>::ends_at(&RangeFull), isize::MAX);
     }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_format_range() {
+        assert_eq!(format_range(&(0..=5)), "0..=5");
+        assert_eq!(format_range(&(-3..=-3)), "-3..=-3");
+        assert_eq!(format_range(&('a'..='c')), "a..=c");
+        assert_eq!(format_range(&(u8::MIN..=u8::MAX)), "0..=255");
+    }
+
+    #[test]
+    fn test_try_from_bounds() {
+        use Bound::{Excluded, Included, Unbounded};
+        assert_eq!(try_from_bounds(Included(3), Included(7)), Ok(3..=7));
+        assert_eq!(try_from_bounds(Included(3), Excluded(7)), Ok(3..=6));
+        assert_eq!(try_from_bounds(Excluded(3), Included(7)), Ok(4..=7));
+        assert_eq!(try_from_bounds(Excluded(3u8), Unbounded), Ok(4..=255));
+        assert_eq!(try_from_bounds(Unbounded, Unbounded), Ok(-128i8..=127));
+        assert_eq!(try_from_bounds(Included(3), Included(3)), Ok(3..=3));
+        assert_eq!(
+            try_from_bounds(Excluded(3), Included(3)),
+            Err(RangeError::Empty)
+        );
+        assert_eq!(
+            try_from_bounds(Included(3), Excluded(3)),
+            Err(RangeError::Empty)
+        );
+        assert_eq!(
+            try_from_bounds(Included(7), Included(3)),
+            Err(RangeError::Empty)
+        );
+        assert_eq!(
+            try_from_bounds(Excluded(u8::MAX), Unbounded),
+            Err(RangeError::Empty)
+        );
+        assert_eq!(
+            try_from_bounds(Unbounded, Excluded(i8::MIN)),
+            Err(RangeError::Empty)
+        );
+    }
+
+    #[test]
+    fn test_contains_all() {
+        assert!((0..=10).contains_all([1, 5, 10]));
+        assert!((0..=10).contains_all([]));
+        assert!(!(0..=10).contains_all([1, 11, 5]));
+        assert!(!(0..10).contains_all([0, 10]));
+        assert!(!(5..5).contains_all([5]));
+        assert!((..).contains_all([i8::MIN, i8::MAX]));
+
+        // stops at the first item outside the range
+        let mut checked = 0;
+        let items = [3, 4, 20, 5, 6].into_iter().inspect(|_| checked += 1);
+        assert!(!(0..=10).contains_all(items));
+        assert_eq!(checked, 3);
+        assert!(!(..0u8).contains_all([0]));
+    }
+
+    #[test]
+    fn test_contains_any() {
+        assert!((0..=10).contains_any([-1, 20, 5]));
+        assert!((0..=10).contains_any([-1, 20, 10]));
+        assert!(!(0..=10).contains_any([]));
+        assert!(!(0..10).contains_any([-1, 10]));
+        assert!(!(5..5).contains_any([5]));
+        assert!((..).contains_any([i8::MIN]));
+
+        // stops at the first item inside the range
+        let mut checked = 0;
+        let items = [-3, 4, 20, 5].into_iter().inspect(|_| checked += 1);
+        assert!((0..=10).contains_any(items));
+        assert_eq!(checked, 2);
+        assert!(!(..0u8).contains_any([0, 1, 255]));
+    }
+
+    #[test]
+    fn test_closest() {
+        assert_eq!((5..=10).closest(&2), 5);
+        assert_eq!((5..=10).closest(&5), 5);
+        assert_eq!((5..=10).closest(&7), 7);
+        assert_eq!((5..=10).closest(&10), 10);
+        assert_eq!((5..=10).closest(&15), 10);
+        assert_eq!((5..11).closest(&15), 10);
+        assert_eq!((..=0u8).closest(&200), 0);
+        assert_eq!((5..5).closest(&7), 7);
+
+        assert_eq!((5..=10).checked_closest(&2), Some(5));
+        assert_eq!((5..=10).checked_closest(&7), Some(7));
+        assert_eq!((5..=10).checked_closest(&15), Some(10));
+        assert_eq!((5..5).checked_closest(&7), None);
+    }
+
+    #[test]
+    fn test_distance_to() {
+        assert_eq!((5..=10).distance_to(&2), Some(3));
+        assert_eq!((5..=10).distance_to(&4), Some(1));
+        assert_eq!((5..=10).distance_to(&5), Some(0));
+        assert_eq!((5..=10).distance_to(&7), Some(0));
+        assert_eq!((5..=10).distance_to(&10), Some(0));
+        assert_eq!((5..=10).distance_to(&14), Some(4));
+        assert_eq!((5..11).distance_to(&11), Some(1));
+        assert_eq!((i8::MAX..).distance_to(&i8::MIN), Some(255));
+        assert_eq!((..=i64::MIN).distance_to(&i64::MAX), Some(u64::MAX as u128));
+        assert_eq!((5..5).distance_to(&5), None);
+    }
+
+    #[test]
+    fn test_range_distance() {
+        assert_eq!((0..=3).range_distance(&(7..=9)), 3);
+        assert_eq!((7..=9).range_distance(&(0..=3)), 3);
+        assert_eq!((0..=3).range_distance(&(5..=9)), 1);
+        assert_eq!((0..=3).range_distance(&(4..=9)), 0);
+        assert_eq!((0..4).range_distance(&(4..=9)), 0);
+        assert_eq!((0..=5).range_distance(&(3..=9)), 0);
+        assert_eq!((0..=10).range_distance(&(3..=4)), 0);
+        assert_eq!((..=0u8).range_distance(&(255..)), 254);
+        assert_eq!((..=i8::MIN).range_distance(&(i8::MAX..)), i8::MAX);
+        assert_eq!((-100i8..=-50).range_distance(&(50..)), 99);
+        assert_eq!((0..=3).range_distance(&(7..7)), 0);
+        assert_eq!((0..0).range_distance(&(7..=9)), 0);
+    }
+
+    #[test]
+    fn test_to_exclusive() {
+        assert_eq!((3..=7).to_exclusive(), Some(3..8));
+        assert_eq!((3..8).to_exclusive(), Some(3..8));
+        assert_eq!((..=10u8).to_exclusive(), Some(0..11));
+        assert_eq!((3u8..).to_exclusive(), None);
+        assert_eq!((..=i8::MAX).to_exclusive(), None);
+        assert_eq!((5..5).to_exclusive(), Some(5..5));
+        assert_eq!(
+            (0..=5).intersection(&(3..)).and_then(|r| r.to_exclusive()),
+            Some(3..6)
+        );
+        assert_eq!(('a'..='c').to_exclusive(), Some('a'..'d'));
+        assert_eq!((..0u8).to_exclusive(), Some(1..1));
+    }
+
+    #[test]
+    fn test_to_inclusive() {
+        assert_eq!((..=10u8).to_inclusive(), 0..=10);
+        assert_eq!((3u8..).to_inclusive(), 3..=255);
+        assert_eq!((2..5).to_inclusive(), 2..=4);
+        assert_eq!((2..=5).to_inclusive(), 2..=5);
+        assert_eq!((..).to_inclusive(), i8::MIN..=i8::MAX);
+        assert_eq!(
+            (Bound::Excluded(2), Bound::Excluded(5)).to_inclusive(),
+            3..=4
+        );
+        assert!((5..5).to_inclusive().is_empty());
+
+        // the half-open counterpart
+        assert_eq!((..=10u8).to_exclusive(), Some(0..11));
+        assert_eq!((3u8..).to_exclusive(), None);
+        assert_eq!((2..5).to_exclusive(), Some(2..5));
+    }
+
+    #[test]
+    fn test_reverse() {
+        assert_eq!(Reverse(5).inc(), Reverse(4));
+        assert_eq!(Reverse(5).dec(), Reverse(6));
+        assert_eq!(Reverse::<u8>::MIN_VALUE, Reverse(u8::MAX));
+        assert_eq!(Reverse::<u8>::MAX_VALUE, Reverse(0));
+        assert_eq!(Reverse(0u8).checked_inc(), None);
+        assert_eq!(Reverse(255u8).checked_dec(), None);
+        assert_eq!(Reverse(255u8).offset_from_min(), 0);
+        assert_eq!(Reverse(0u8).offset_from_min(), 255);
+        assert_eq!(Reverse::<i8>::from_offset(0), Reverse(i8::MAX));
+        assert_eq!(Reverse::<i8>::from_offset(255), Reverse(i8::MIN));
+
+        assert_eq!(
+            (Reverse(10)..=Reverse(5)).intersection(&(Reverse(7)..=Reverse(0))),
+            Some(Reverse(7)..=Reverse(5))
+        );
+        assert_eq!((Reverse(10)..Reverse(5)).ends_at(), Reverse(6));
+        assert_eq!((..=Reverse(250u8)).starts_at(), Reverse(255));
+        assert_eq!((Reverse(10)..=Reverse(5)).cardinality(), 6);
+        assert_eq!(
+            (Reverse(3)..=Reverse(0)).values().collect::<Vec<_>>(),
+            [Reverse(3), Reverse(2), Reverse(1), Reverse(0)]
+        );
+    }
+
+    #[test]
+    fn test_scale() {
+        assert_eq!((2..=4).scale(3), 6..=12);
+        assert_eq!((2..=4).scale(-1), -4..=-2);
+        assert_eq!((-2..=4).scale(-2), -8..=4);
+        assert_eq!((2..=4).scale(0), 0..=0);
+        assert_eq!((2..=4).scale(1), 2..=4);
+        assert_eq!((2..5).scale(2), 4..=8);
+        assert_eq!((100u8..=200).scale(2), 200..=255);
+        assert_eq!((-100i8..=100).scale(2), -128..=127);
+        assert_eq!((-100i8..=100).scale(-2), -128..=127);
+        assert_eq!((i8::MIN..=0).scale(-1), 0..=127);
+        assert!((5..5).scale(-3).is_empty());
+
+        assert_eq!((2..=4).checked_scale(3), Some(6..=12));
+        assert_eq!((2..=4).checked_scale(-1), Some(-4..=-2));
+        assert_eq!((2..=4).checked_scale(0), Some(0..=0));
+        assert_eq!((100u8..=127).checked_scale(2), Some(200..=254));
+        assert_eq!((100u8..=200).checked_scale(2), None);
+        assert_eq!((i8::MIN..=0).checked_scale(-1), None);
+        assert_eq!((-64i8..=63).checked_scale(2), Some(-128..=126));
+        assert_eq!((-64i8..=-1).checked_scale(-2), None);
+        assert_eq!((-63i8..=-1).checked_scale(-2), Some(2..=126));
+        assert!((5..5).checked_scale(-3).is_some_and(|r| r.is_empty()));
+    }
+
+    #[test]
+    fn test_map_bounds() {
+        assert_eq!((0usize..=5).map_bounds(|x| x as i64), 0i64..=5);
+        assert_eq!((0usize..6).map_bounds(|x| x as i64 * 2), 0..=10);
+        assert_eq!((..=3u8).map_bounds(u32::from), 0..=3);
+        assert_eq!(('a'..='c').map_bounds(|c| c as u32), 97..=99);
+        // not monotonic, so the result is reversed
+        assert!((1..=5).map_bounds(|x: i32| -x).is_empty());
+        assert!((..0u8).map_bounds(u16::from).is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_setminus_all() {
+        assert_eq!(
+            (0..=10).setminus_all([2..=3, 6..=7]),
+            [0..=1, 4..=5, 8..=10]
+        );
+        assert_eq!(
+            (0..=10).setminus_all([6..=7, 2..=3]),
+            [0..=1, 4..=5, 8..=10]
+        );
+        assert_eq!((0..=10).setminus_all(Vec::<RangeFull>::new()), [0..=10]);
+        assert_eq!((0..=10).setminus_all([0..5, 5..11]), []);
+        assert_eq!((0..=10).setminus_all([-5..=0, 10..=20]), [1..=9]);
+        assert_eq!((0..=10).setminus_all([20..30, 3..3]), [0..=10]);
+        assert_eq!((5..5).setminus_all([0..=1, 3..=4]), []);
+        assert_eq!((..).setminus_all([0u8..=0, 255..=255]), [1..=254]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_union_all() {
+        assert_eq!(union_all([0..=3, 5..=6, 2..=4]), [0..=6]);
+        assert_eq!(union_all([0..=3, 6..=7, 2..=4]), [0..=4, 6..=7]);
+        assert_eq!(union_all([10..20, 0..5, 5..10]), [0..=19]);
+        assert_eq!(union_all([3..3, 0..2]), [0..=1]);
+        assert_eq!(
+            union_all(Vec::<RangeFull>::new()),
+            Vec::<std::ops::RangeInclusive<i32>>::new()
+        );
+        assert_eq!(
+            union_all([
+                (Bound::Excluded(250u8), Bound::Unbounded),
+                (Bound::Unbounded, Bound::Included(251))
+            ]),
+            [0..=255]
+        );
+    }
+
+    #[test]
+    fn test_partition_point() {
+        assert_eq!((0..=1000).partition_point(|x| x * x >= 500), Some(23));
+        assert_eq!((0..=1000).partition_point(|&x| x >= 0), Some(0));
+        assert_eq!((0..=1000).partition_point(|&x| x >= 1000), Some(1000));
+        assert_eq!((0..=1000).partition_point(|&x| x > 1000), None);
+        assert_eq!((0..1000).partition_point(|&x| x >= 1000), None);
+        assert_eq!((5..5).partition_point(|_| true), None);
+        assert_eq!((7..=7).partition_point(|_| true), Some(7));
+        assert_eq!(
+            (..).partition_point(|&x: &u128| x > u128::MAX - 3),
+            Some(u128::MAX - 2)
+        );
+        assert_eq!((..).partition_point(|&x: &i64| x >= -5), Some(-5));
+        assert_eq!(('a'..='z').partition_point(|&c| c > 'q'), Some('r'));
+    }
+
+    #[test]
+    fn test_bisect() {
+        assert_eq!((0..=9).bisect(|&x| x >= 5), (Some(0..=4), Some(5..=9)));
+        assert_eq!((0..=9).bisect(|&x| x >= 9), (Some(0..=8), Some(9..=9)));
+        assert_eq!((0..=9).bisect(|&x| x >= 0), (None, Some(0..=9)));
+        assert_eq!((0..=9).bisect(|_| true), (None, Some(0..=9)));
+        assert_eq!((0..=9).bisect(|_| false), (Some(0..=9), None));
+        assert_eq!((0..10).bisect(|&x| x >= 10), (Some(0..=9), None));
+        assert_eq!((5..5).bisect(|_| false), (None, None));
+        assert_eq!(
+            (..).bisect(|&x: &u8| x >= 128),
+            (Some(0..=127), Some(128..=255))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_rotate() {
+        assert_eq!((8..=10).rotate(5, &(0..=11)), [1..=3]);
+        assert_eq!((8..=10).rotate(3, &(0..=11)), [0..=1, 11..=11]);
+        assert_eq!((8..=10).rotate(4, &(0..=11)), [0..=2]);
+        assert_eq!((8..=10).rotate(1, &(0..=11)), [9..=11]);
+        assert_eq!((8..=10).rotate(0, &(0..=11)), [8..=10]);
+        assert_eq!((8..=10).rotate(12, &(0..=11)), [8..=10]);
+        assert_eq!((8..=10).rotate(27, &(0..=11)), [0..=1, 11..=11]);
+        assert_eq!((1..=3).rotate(-2, &(0..=11)), [0..=1, 11..=11]);
+        assert_eq!((1..=3).rotate(-1, &(0..=11)), [0..=2]);
+        assert_eq!((1..=3).rotate(-14, &(0..=11)), [0..=1, 11..=11]);
+        // a domain not starting at zero
+        assert_eq!((18..=20).rotate(5, &(10..=21)), [11..=13]);
+        assert_eq!((-2..=0).rotate(2, &(-3..=1)), [-3..=-3, 0..=1]);
+        // elements outside of the domain are dropped, the whole domain stays the same
+        assert_eq!((8..=15).rotate(2, &(0..=11)), [0..=1, 10..=11]);
+        assert!((20..=25).rotate(2, &(0..=11)).is_empty());
+        assert_eq!((0..=11).rotate(5, &(0..=11)), [0..=11]);
+        // the type boundaries
+        assert_eq!((250u8..=255).rotate(3, &(..)), [0..=2, 253..=255]);
+        assert_eq!((250u8..=255).rotate(255, &(..)), [249..=254]);
+        assert_eq!((-128i8..=-127).rotate(-1, &(..)), [-128..=-128, 127..=127]);
+        assert_eq!(
+            (0..=1).rotate(u128::MAX, &(..)),
+            [0..=0, u128::MAX..=u128::MAX]
+        );
+        assert_eq!((5..=6).rotate(-7i128, &(..)), [-2..=-1]);
+    }
+
+    #[test]
+    fn test_align_outward() {
+        assert_eq!((5..=17).align_outward(8), 0..=23);
+        assert_eq!((8..=15).align_outward(8), 8..=15);
+        assert_eq!((8..16).align_outward(8), 8..=15);
+        assert_eq!((8..=16).align_outward(8), 8..=23);
+        assert_eq!((3..=3).align_outward(1), 3..=3);
+        assert_eq!((-3..=3).align_outward(4), -4..=3);
+        assert_eq!((-9..=-5).align_outward(4), -12..=-5);
+        assert_eq!((-8..=-1).align_outward(4), -8..=-1);
+        assert_eq!((250u8..=251).align_outward(8), 248..=255);
+        assert_eq!((-128i8..=-127).align_outward(3), -128..=-127);
+        assert!((5..5).align_outward(8).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "alignment must be positive")]
+    fn test_align_outward_zero() {
+        (0..=10).align_outward(0);
+    }
+
+    #[test]
+    fn test_align_start_down() {
+        assert_eq!((5..=17).align_start_down(8), 0..=17);
+        assert_eq!((8..=17).align_start_down(8), 8..=17);
+        assert_eq!((0..=3).align_start_down(8), 0..=3);
+        assert_eq!((-3..=3).align_start_down(4), -4..=3);
+        assert_eq!((-8..=3).align_start_down(4), -8..=3);
+        assert_eq!((4096..=8191).align_start_down(4096), 4096..=8191);
+        assert_eq!((5000..=8191).align_start_down(4096), 4096..=8191);
+        assert_eq!((-128i8..=-127).align_start_down(3), -128..=-127);
+        assert!((5..5).align_start_down(8).is_empty());
+    }
+
+    #[test]
+    fn test_align_end_up() {
+        assert_eq!((5..=17).align_end_up(8), 5..=23);
+        assert_eq!((5..=15).align_end_up(8), 5..=15);
+        assert_eq!((5..16).align_end_up(8), 5..=15);
+        assert_eq!((-9..=-5).align_end_up(4), -9..=-5);
+        assert_eq!((-9..=-6).align_end_up(4), -9..=-5);
+        assert_eq!((250u8..=251).align_end_up(8), 250..=255);
+        assert!((5..5).align_end_up(8).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "alignment must be positive")]
+    fn test_align_start_down_zero() {
+        (0..=10).align_start_down(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "alignment must be positive")]
+    fn test_align_end_up_zero() {
+        (0..=10).align_end_up(0);
+    }
+
+    #[test]
+    fn test_dilate_to_multiple() {
+        assert_eq!((0..=9).dilate_to_multiple(4), 0..=11);
+        assert_eq!((0..=11).dilate_to_multiple(4), 0..=11);
+        assert_eq!((0..12).dilate_to_multiple(4), 0..=11);
+        assert_eq!((3..=3).dilate_to_multiple(4), 3..=6);
+        assert_eq!((3..=7).dilate_to_multiple(1), 3..=7);
+        assert_eq!((-5..=-3).dilate_to_multiple(2), -5..=-2);
+        assert_eq!((250u8..=252).dilate_to_multiple(8), 250..=255);
+        assert_eq!((..=254u8).dilate_to_multiple(2), 0..=255);
+        assert!((5..5).dilate_to_multiple(8).is_empty());
+        assert_eq!((i8::MIN..=i8::MAX).dilate_to_multiple(3), i8::MIN..=i8::MAX);
+        assert_eq!((-100i8..=100).dilate_to_multiple(100), -100..=127);
+        assert_eq!((-100i8..=99).dilate_to_multiple(100), -100..=99);
+        assert_eq!((-128i8..=-127).dilate_to_multiple(127), -128..=-2);
+    }
+
+    #[test]
+    #[should_panic(expected = "block size must be positive")]
+    fn test_dilate_to_multiple_zero() {
+        (0..=10).dilate_to_multiple(0);
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_sample_uniform() {
+        use rand::{rngs::SmallRng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(42);
+        let mut seen = [false; 6];
+        for _ in 0..1000 {
+            let x = (1..=6).sample_uniform(&mut rng).unwrap();
+            assert!((1..=6).contains(&x));
+            seen[x as usize - 1] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+        for _ in 0..1000 {
+            assert!((-3..3)
+                .sample_uniform(&mut rng)
+                .is_some_and(|x| (-3..3).contains(&x)));
+            assert!(('a'..='e')
+                .sample_uniform(&mut rng)
+                .is_some_and(|c| ('a'..='e').contains(&c)));
+        }
+        assert!((i64::MIN..=i64::MAX).sample_uniform(&mut rng).is_some());
+        assert!(RangeUtil::<u128>::sample_uniform(&(..), &mut rng).is_some());
+        assert_eq!((7..=7).sample_uniform(&mut rng), Some(7));
+        assert_eq!((5..5).sample_uniform(&mut rng), None);
+    }
+
+    #[test]
+    #[cfg(all(feature = "rand", feature = "alloc"))]
+    fn test_sample_distinct() {
+        use rand::{rngs::SmallRng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(42);
+        for k in 0..=10 {
+            let sample = (0..10).sample_distinct(&mut rng, k);
+            assert_eq!(sample.len(), k);
+            assert!(sample.windows(2).all(|w| w[0] < w[1]));
+            assert!(sample.iter().all(|x| (0..10).contains(x)));
+        }
+        for _ in 0..100 {
+            let sample = (-50..=50).sample_distinct(&mut rng, 20);
+            assert_eq!(sample.len(), 20);
+            assert!(sample.windows(2).all(|w| w[0] < w[1]));
+            assert!(sample.iter().all(|x| (-50..=50).contains(x)));
+        }
+        assert_eq!((0..5).sample_distinct(&mut rng, 10), [0, 1, 2, 3, 4]);
+        assert!((5..5).sample_distinct(&mut rng, 10).is_empty());
+        assert_eq!(
+            RangeUtil::<u128>::sample_distinct(&(..), &mut rng, 3).len(),
+            3
+        );
+        assert_eq!((i64::MIN..=i64::MAX).sample_distinct(&mut rng, 3).len(), 3);
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_finite() {
+        assert_eq!((3u8..).finite().into_iter().count(), 253);
+        assert_eq!((3u8..).finite().into_iter().next_back(), Some(255));
+        assert_eq!((..=2i8).finite().into_iter().next(), Some(i8::MIN));
+        assert_eq!((2..5).finite().into_iter().collect::<Vec<_>>(), [2, 3, 4]);
+        assert_eq!((2..5).finite(), (2..=4).finite());
+        assert_eq!((5..5).finite(), (3..=0).finite());
+        assert_eq!((..0u8).finite(), (7..3).finite());
+        assert_eq!((5u8..5).finite().start(), &u8::MAX);
+        assert!((5..5).finite().is_empty());
+        assert_eq!((2..5).finite().start(), &2);
+        assert_eq!((2..5).finite().end(), &4);
+        assert_eq!((5..5).finite().into_iter().count(), 0);
+        assert_eq!((3u8..).finite().cardinality(), 253);
+        assert_eq!((3u8..).finite().intersection(&(..10)), Some(3..=9));
+
+        let mut sum = 0;
+        for x in (250u8..).finite() {
+            sum += x as u32;
+        }
+        assert_eq!(sum, 250 + 251 + 252 + 253 + 254 + 255);
+
+        assert_eq!((3u8..).finite().into_iter().size_hint(), (253, Some(253)));
+        assert_eq!((5..5).finite().into_iter().size_hint(), (0, Some(0)));
+        assert_eq!((3u8..).finite().into_iter().len(), 253);
+        assert_eq!(RangeUtil::<i16>::finite(&(..)).into_iter().len(), 65536);
+        let mut values = (0u8..=9).values();
+        assert_eq!(values.len(), 10);
+        values.next();
+        values.next_back();
+        assert_eq!(values.len(), 8);
+        assert_eq!(values.by_ref().count(), 8);
+        assert_eq!(values.len(), 0);
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(RangeUtil::<u32>::values(&(..)).len(), 1 << 32);
+        assert_eq!(('a'..='z').values().len(), 26);
+        assert_eq!(RangeUtil::<char>::values(&(..)).len(), 0x10F800);
+        let mut values = (0..3).values();
+        values.next();
+        assert_eq!(values.size_hint(), (2, Some(2)));
+        assert_eq!(
+            RangeUtil::<u128>::values(&(..)).size_hint(),
+            (usize::MAX, None)
+        );
+        assert_eq!((..0u8).finite().into_iter().count(), 0);
+    }
+
+    #[test]
+    fn test_range_eq() {
+        assert!((0..4).range_eq(&(0..=3)));
+        assert!((0..=3).range_eq(&(0..4)));
+        assert!(!(0..4).range_eq(&(0..=4)));
+        assert!(!(0..4).range_eq(&(1..4)));
+        assert!((..4u8).range_eq(&(0..=3)));
+        assert!((250u8..).range_eq(&(250..=255)));
+        assert!((..).range_eq(&(i8::MIN..=i8::MAX)));
+        assert!((Bound::Excluded(0), Bound::Included(3)).range_eq(&(1..4)));
+        assert!((5..5).range_eq(&(7..7)));
+        assert!((5..5).range_eq(&(Bound::Excluded(3), Bound::Excluded(4))));
+        assert!(!(5..5).range_eq(&(5..=5)));
+    }
+
+    #[test]
+    fn test_relation() {
+        assert_eq!((0..=3).relation(&(5..=9)), RangeRelation::Disjoint);
+        assert_eq!((5..=9).relation(&(0..=3)), RangeRelation::Disjoint);
+        assert_eq!((0..=3).relation(&(4..=9)), RangeRelation::Adjacent);
+        assert_eq!((4..=9).relation(&(0..4)), RangeRelation::Adjacent);
+        assert_eq!((0..=5).relation(&(3..=9)), RangeRelation::Overlapping);
+        assert_eq!((3..=9).relation(&(0..=5)), RangeRelation::Overlapping);
+        assert_eq!((0..=9).relation(&(3..=5)), RangeRelation::Contains);
+        assert_eq!((0..=9).relation(&(0..=5)), RangeRelation::Contains);
+        assert_eq!((3..=5).relation(&(0..=9)), RangeRelation::ContainedBy);
+        assert_eq!((3..=9).relation(&(..)), RangeRelation::ContainedBy);
+        assert_eq!((0..4).relation(&(0..=3)), RangeRelation::Equal);
+        assert_eq!((5..5).relation(&(7..7)), RangeRelation::Equal);
+        assert_eq!((0..=9).relation(&(5..5)), RangeRelation::Disjoint);
+        assert_eq!((5..5).relation(&(0..=9)), RangeRelation::Disjoint);
+    }
+
+    #[test]
+    fn test_is_unbounded() {
+        assert!((3u8..).is_end_unbounded());
+        assert!(!(3u8..).is_start_unbounded());
+        assert!(!(3u8..=u8::MAX).is_end_unbounded());
+        assert!((..3).is_start_unbounded());
+        assert!(!(..3).is_end_unbounded());
+        assert!(!(i32::MIN..3).is_start_unbounded());
+        assert!(RangeUtil::<i8>::is_start_unbounded(&RangeFull));
+        assert!(RangeUtil::<i8>::is_end_unbounded(&RangeFull));
+        assert!(!(0..=3).is_start_unbounded());
+        assert!((Bound::Unbounded, Bound::Excluded(3)).is_start_unbounded());
+        assert!(!(Bound::Unbounded, Bound::Excluded(3)).is_end_unbounded());
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_normalize() {
+        assert_eq!((5..=3).normalize(), None);
+        assert_eq!((0..0).normalize(), None);
+        assert_eq!((..0u8).normalize(), None);
+        assert_eq!(RangeUtil::<u8>::normalize(&(..)), Some(0..=255));
+        assert_eq!(RangeUtil::<i8>::normalize(&(..)), Some(-128..=127));
+        assert_eq!((0..4).normalize(), Some(0..=3));
+        assert_eq!((3..=3).normalize(), Some(3..=3));
+        assert_eq!((250u8..).normalize(), Some(250..=255));
+    }
+
+    #[test]
+    fn test_try_merge() {
+        assert_eq!((0..=3).try_merge(&(3..=6)), Some(0..=6));
+        assert_eq!((3..=6).try_merge(&(0..=3)), Some(0..=6));
+        assert_eq!((0..=3).try_merge(&(4..=6)), Some(0..=6));
+        assert_eq!((0..=10).try_merge(&(4..=6)), Some(0..=10));
+        assert_eq!((0..=3).try_merge(&(10..=12)), None);
+        assert_eq!((0..=3).try_merge(&(5..=6)), None);
+        assert_eq!((0..=3).try_merge(&(7..7)), Some(0..=3));
+        assert_eq!((7..7).try_merge(&(0..=3)), Some(0..=3));
+        assert_eq!((7..7).try_merge(&(0..0)), None);
+        assert_eq!((..=0u8).try_merge(&(1u8..)), Some(0..=255));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_includes_mask() {
+        assert_eq!(
+            (0..=10).includes_mask([5, 20, 0, -1, 10]),
+            [true, false, true, false, true]
+        );
+        assert_eq!((0..10).includes_mask([10, 9]), [false, true]);
+        assert!((0..=10).includes_mask([]).is_empty());
+        assert_eq!((5..5).includes_mask([5, 4]), [false, false]);
+        assert_eq!((..0u8).includes_mask([0, 1]), [false, false]);
+    }
+
+    #[test]
+    fn test_includes_count() {
+        assert_eq!((0..=10).includes_count([5, 20, 0, -1, 10]), 3);
+        assert_eq!((0..10).includes_count([10, 9]), 1);
+        assert_eq!((0..=10).includes_count([]), 0);
+        assert_eq!((5..5).includes_count([5, 4]), 0);
+        assert_eq!((..).includes_count([u8::MIN, u8::MAX]), 2);
+        assert_eq!((..0u8).includes_count([0, 1, 255]), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_retain_intersecting() {
+        let ranges = [0..=3, 4..=6, 8..=9, 5..=5, 20..=30];
+        assert_eq!(
+            retain_intersecting(&ranges, &(5..=8)),
+            [4..=6, 8..=9, 5..=5]
+        );
+        // adjacent, but no common elements
+        assert_eq!(retain_intersecting(&ranges, &(10..20)), []);
+        assert_eq!(retain_intersecting(&ranges, &(7..=7)), []);
+        assert_eq!(retain_intersecting(&ranges, &(3..4)), [0..=3]);
+        assert_eq!(retain_intersecting(&ranges, &(..)), ranges);
+        assert_eq!(retain_intersecting(&ranges, &(5..5)), []);
+        assert!(retain_intersecting::<i32>(&[], &(..)).is_empty());
+    }
 }
@@ -0,0 +1,105 @@
+use core::{
+    fmt::{self, Display},
+    ops::{Bound, RangeInclusive},
+    str::FromStr,
+};
+
+use crate::{BasicNum, RangeUtil};
+
+/// Error returned by `parse_range`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseRangeError {
+    /// There is no `..` between the bounds, e.g. `abc`
+    MissingSeparator,
+    /// The start can't be parsed as `T`, e.g. `a..3` for numbers
+    InvalidStart,
+    /// The end can't be parsed as `T` or is missing after `..=`, e.g. `3..=`
+    InvalidEnd,
+    /// The range contains no elements, e.g. `7..3` or `3..3`
+    Empty,
+}
+impl Display for ParseRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::MissingSeparator => "missing `..` separator",
+            Self::InvalidStart => "invalid start bound",
+            Self::InvalidEnd => "invalid end bound",
+            Self::Empty => "range is empty",
+        })
+    }
+}
+impl core::error::Error for ParseRangeError {}
+
+/// Parses `start..=end`, `start..end`, `start..`, `..=end`, `..end` or `..` into the resolved range, e.g. `"3..7"` is `3..=6` and `"3.."` for `u8` is `3..=255`
+///
+/// Whitespace around the bounds is ignored. Unbounded sides resolve to `T::MIN_VALUE`/`T::MAX_VALUE`, ranges without elements are rejected.
+pub fn parse_range<T: Ord + Clone + BasicNum + FromStr>(
+    s: &str,
+) -> Result<RangeInclusive<T>, ParseRangeError> {
+    let (start, end) = s
+        .split_once("..")
+        .ok_or(ParseRangeError::MissingSeparator)?;
+    let start: Bound<T> = match start.trim() {
+        "" => Bound::Unbounded,
+        start => Bound::Included(start.parse().map_err(|_| ParseRangeError::InvalidStart)?),
+    };
+    let parse_end = |end: &str| end.trim().parse().map_err(|_| ParseRangeError::InvalidEnd);
+    let end: Bound<T> = match end.strip_prefix('=') {
+        Some(end) => Bound::Included(parse_end(end)?),
+        None if end.trim().is_empty() => Bound::Unbounded,
+        None => Bound::Excluded(parse_end(end)?),
+    };
+    let range = (start, end);
+    if RangeUtil::<T>::is_empty(&range) {
+        return Err(ParseRangeError::Empty);
+    }
+    Ok(range.starts_at()..=range.ends_at())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_range, ParseRangeError};
+
+    #[test]
+    fn test_parse_range() {
+        assert_eq!(parse_range::<i32>("3..=7"), Ok(3..=7));
+        assert_eq!(parse_range::<i32>("3..7"), Ok(3..=6));
+        assert_eq!(parse_range::<u8>("3.."), Ok(3..=255));
+        assert_eq!(parse_range::<i8>("..=7"), Ok(-128..=7));
+        assert_eq!(parse_range::<i8>("..7"), Ok(-128..=6));
+        assert_eq!(parse_range::<u8>(".."), Ok(0..=255));
+        assert_eq!(parse_range::<i32>("-5..=-1"), Ok(-5..=-1));
+        assert_eq!(parse_range::<i32>(" 3 ..= 7 "), Ok(3..=7));
+        assert_eq!(parse_range::<char>("a..=c"), Ok('a'..='c'));
+        assert_eq!(parse_range::<i32>("5..=5"), Ok(5..=5));
+    }
+
+    #[test]
+    fn test_parse_range_errors() {
+        assert_eq!(parse_range::<i32>("7..3"), Err(ParseRangeError::Empty));
+        assert_eq!(parse_range::<i32>("3..3"), Err(ParseRangeError::Empty));
+        assert_eq!(parse_range::<u8>("..0"), Err(ParseRangeError::Empty));
+        assert_eq!(
+            parse_range::<i32>("abc"),
+            Err(ParseRangeError::MissingSeparator)
+        );
+        assert_eq!(
+            parse_range::<i32>(""),
+            Err(ParseRangeError::MissingSeparator)
+        );
+        assert_eq!(
+            parse_range::<i32>("a..3"),
+            Err(ParseRangeError::InvalidStart)
+        );
+        assert_eq!(parse_range::<i32>("3..b"), Err(ParseRangeError::InvalidEnd));
+        assert_eq!(parse_range::<i32>("3..="), Err(ParseRangeError::InvalidEnd));
+        assert_eq!(
+            parse_range::<i32>("1..2..3"),
+            Err(ParseRangeError::InvalidEnd)
+        );
+        assert_eq!(
+            parse_range::<u8>("0..=256"),
+            Err(ParseRangeError::InvalidEnd)
+        );
+    }
+}
@@ -0,0 +1,385 @@
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+
+use crate::{BasicNum, RangeUtil};
+
+/// A map from disjoint ranges to values, e.g. IP ranges to countries
+///
+/// The ranges are kept sorted by their start. Inserting overwrites the overlapping parts of existing entries, which are trimmed (or split) to the elements outside the new range; adjacent entries are never merged, even if their values are equal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeMap<T, V> {
+    entries: Vec<(RangeInclusive<T>, V)>,
+}
+impl<T, V> Default for RangeMap<T, V> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+}
+impl<T: Ord + Clone + BasicNum, V> RangeMap<T, V> {
+    /// An empty map
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Maps all elements of `range` to `value`, overwriting the previous values of these elements
+    ///
+    /// Existing entries overlapping `range` are trimmed to their elements outside of it, e.g. inserting `3..=5` into `[0..=9 => a]` results in `[0..=2 => a, 3..=5 => b, 6..=9 => a]`. Empty ranges are ignored.
+    pub fn insert(&mut self, range: impl RangeUtil<T>, value: V)
+    where
+        V: Clone,
+    {
+        let range = range.to_inclusive();
+        if range.is_empty() {
+            return;
+        }
+        // all entries in `lo..hi` intersect `range`
+        let lo = self
+            .entries
+            .partition_point(|(r, _)| r.end() < range.start());
+        let hi = self
+            .entries
+            .partition_point(|(r, _)| r.start() <= range.end());
+        // `dec`/`inc` can't overflow since there is an element before/after `range`
+        let left = self.entries[lo..hi]
+            .first()
+            .filter(|(r, _)| r.start() < range.start())
+            .map(|(r, v)| (r.start().clone()..=range.start().dec(), v.clone()));
+        let right = self.entries[lo..hi]
+            .last()
+            .filter(|(r, _)| r.end() > range.end())
+            .map(|(r, v)| (range.end().inc()..=r.end().clone(), v.clone()));
+        self.entries.splice(
+            lo..hi,
+            left.into_iter().chain([(range, value)]).chain(right),
+        );
+    }
+    /// The value of the entry containing `x`, `None` if `x` is in no range
+    pub fn get(&self, x: &T) -> Option<&V> {
+        self.get_key_value(x).map(|(_, v)| v)
+    }
+    /// The range and value of the entry containing `x`, e.g. for the offset of `x` in its range; `None` if `x` is in no range
+    pub fn get_key_value(&self, x: &T) -> Option<(&RangeInclusive<T>, &V)> {
+        let i = self.entries.partition_point(|(r, _)| r.end() < x);
+        self.entries
+            .get(i)
+            .filter(|(r, _)| r.start() <= x)
+            .map(|(r, v)| (r, v))
+    }
+    /// The entry for exactly the elements of `range`, for in-place updates like `HashMap::entry`
+    ///
+    /// Only an existing entry with the same elements (see `RangeUtil::range_eq`) is occupied. Any other `range` is vacant, even if it overlaps existing entries; inserting into it overwrites them like `insert`, see `VacantEntry::overlapping`. `None` if `range` is empty, since there is no entry without elements.
+    pub fn entry(&mut self, range: impl RangeUtil<T>) -> Option<Entry<'_, T, V>> {
+        let range = range.normalize()?;
+        let i = self
+            .entries
+            .partition_point(|(r, _)| r.start() < range.start());
+        Some(if self.entries.get(i).is_some_and(|(r, _)| r == &range) {
+            Entry::Occupied(OccupiedEntry {
+                map: self,
+                index: i,
+            })
+        } else {
+            Entry::Vacant(VacantEntry { map: self, range })
+        })
+    }
+    /// Whether the map contains no entries
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+    /// The entries of the map, sorted by their start
+    pub fn iter(&self) -> impl Iterator<Item = (&RangeInclusive<T>, &V)> {
+        self.entries.iter().map(|(r, v)| (r, v))
+    }
+}
+
+/// An entry of a `RangeMap`, see `RangeMap::entry`
+#[derive(Debug)]
+pub enum Entry<'a, T, V> {
+    /// The map has an entry with exactly these elements
+    Occupied(OccupiedEntry<'a, T, V>),
+    /// The map has no entry with exactly these elements, but may have overlapping ones
+    Vacant(VacantEntry<'a, T, V>),
+}
+impl<'a, T: Ord + Clone + BasicNum, V> Entry<'a, T, V> {
+    /// The range of the entry
+    pub fn key(&self) -> &RangeInclusive<T> {
+        match self {
+            Self::Occupied(entry) => entry.key(),
+            Self::Vacant(entry) => entry.key(),
+        }
+    }
+    /// Calls `f` with the value if the entry is occupied
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let Self::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+    /// The value of the occupied entry, otherwise inserts `default`
+    pub fn or_insert(self, default: V) -> &'a mut V
+    where
+        V: Clone,
+    {
+        self.or_insert_with(|| default)
+    }
+    /// The value of the occupied entry, otherwise inserts the result of `default`
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V
+    where
+        V: Clone,
+    {
+        match self {
+            Self::Occupied(entry) => entry.into_mut(),
+            Self::Vacant(entry) => entry.insert(default()),
+        }
+    }
+}
+
+/// An entry of a `RangeMap` with exactly the requested elements, see `RangeMap::entry`
+#[derive(Debug)]
+pub struct OccupiedEntry<'a, T, V> {
+    map: &'a mut RangeMap<T, V>,
+    index: usize,
+}
+impl<'a, T, V> OccupiedEntry<'a, T, V> {
+    /// The range of the entry
+    pub fn key(&self) -> &RangeInclusive<T> {
+        &self.map.entries[self.index].0
+    }
+    /// The value of the entry
+    pub fn get(&self) -> &V {
+        &self.map.entries[self.index].1
+    }
+    /// The value of the entry
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.map.entries[self.index].1
+    }
+    /// The value of the entry, borrowed from the map
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.map.entries[self.index].1
+    }
+    /// Replaces the value of the entry, returning the previous one
+    pub fn insert(&mut self, value: V) -> V {
+        core::mem::replace(self.get_mut(), value)
+    }
+    /// Removes the entry from the map, returning its range and value
+    pub fn remove(self) -> (RangeInclusive<T>, V) {
+        self.map.entries.remove(self.index)
+    }
+}
+
+/// An entry of a `RangeMap` without an entry for exactly the requested elements, see `RangeMap::entry`
+#[derive(Debug)]
+pub struct VacantEntry<'a, T, V> {
+    map: &'a mut RangeMap<T, V>,
+    range: RangeInclusive<T>,
+}
+impl<'a, T: Ord + Clone + BasicNum, V> VacantEntry<'a, T, V> {
+    /// The range of the entry
+    pub fn key(&self) -> &RangeInclusive<T> {
+        &self.range
+    }
+    /// The existing entries intersecting the range, which are trimmed when inserting
+    pub fn overlapping(&self) -> impl Iterator<Item = (&RangeInclusive<T>, &V)> {
+        let entries = &self.map.entries;
+        // all entries in `lo..hi` intersect the range, like in `RangeMap::insert`
+        let lo = entries.partition_point(|(r, _)| r.end() < self.range.start());
+        let hi = entries.partition_point(|(r, _)| r.start() <= self.range.end());
+        entries[lo..hi].iter().map(|(r, v)| (r, v))
+    }
+    /// Maps the range to `value` like `RangeMap::insert` and returns the inserted value
+    pub fn insert(self, value: V) -> &'a mut V
+    where
+        V: Clone,
+    {
+        let start = self.range.start().clone();
+        self.map.insert(self.range, value);
+        // overlapping entries were trimmed, so the new entry is the first one not ending before `start`
+        let i = self.map.entries.partition_point(|(r, _)| r.end() < &start);
+        &mut self.map.entries[i].1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{ops::RangeInclusive, vec::Vec};
+
+    use super::{Entry, RangeMap};
+
+    fn entries(map: &RangeMap<i32, char>) -> Vec<(RangeInclusive<i32>, char)> {
+        map.iter().map(|(r, &v)| (r.clone(), v)).collect()
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut map = RangeMap::new();
+        map.insert(0..=9, 'a');
+        assert_eq!(entries(&map), [(0..=9, 'a')]);
+        map.insert(3..=5, 'b');
+        assert_eq!(entries(&map), [(0..=2, 'a'), (3..=5, 'b'), (6..=9, 'a')]);
+        map.insert(20..25, 'c');
+        map.insert(8..=21, 'd');
+        assert_eq!(
+            entries(&map),
+            [
+                (0..=2, 'a'),
+                (3..=5, 'b'),
+                (6..=7, 'a'),
+                (8..=21, 'd'),
+                (22..=24, 'c')
+            ]
+        );
+        map.insert(3..=5, 'e');
+        assert_eq!(
+            entries(&map),
+            [
+                (0..=2, 'a'),
+                (3..=5, 'e'),
+                (6..=7, 'a'),
+                (8..=21, 'd'),
+                (22..=24, 'c')
+            ]
+        );
+        // adjacent entries aren't merged
+        map.insert(25..=26, 'c');
+        assert_eq!(map.iter().count(), 6);
+        map.insert(30..30, 'f');
+        assert_eq!(map.iter().count(), 6);
+        map.insert(-5..=100, 'g');
+        assert_eq!(entries(&map), [(-5..=100, 'g')]);
+
+        let mut map = RangeMap::new();
+        map.insert(.., 0);
+        map.insert(i8::MIN..=i8::MIN, 1);
+        map.insert(i8::MAX.., 2);
+        assert_eq!(
+            map.iter().map(|(r, &v)| (r.clone(), v)).collect::<Vec<_>>(),
+            [(-128..=-128, 1), (-127..=126, 0), (127..=127, 2)]
+        );
+    }
+
+    #[test]
+    fn test_get() {
+        let mut map = RangeMap::new();
+        assert!(map.is_empty());
+        assert_eq!(map.get(&0), None);
+        map.insert(0..=9, 'a');
+        map.insert(20..=29, 'b');
+        map.insert(5..=5, 'c');
+        assert!(!map.is_empty());
+        assert_eq!(map.get(&0), Some(&'a'));
+        assert_eq!(map.get(&4), Some(&'a'));
+        assert_eq!(map.get(&5), Some(&'c'));
+        assert_eq!(map.get(&6), Some(&'a'));
+        assert_eq!(map.get(&9), Some(&'a'));
+        assert_eq!(map.get(&10), None);
+        assert_eq!(map.get(&15), None);
+        assert_eq!(map.get(&19), None);
+        assert_eq!(map.get(&20), Some(&'b'));
+        assert_eq!(map.get(&29), Some(&'b'));
+        assert_eq!(map.get(&30), None);
+        assert_eq!(map.get(&-1), None);
+    }
+
+    #[test]
+    fn test_get_key_value() {
+        let mut map = RangeMap::new();
+        assert_eq!(map.get_key_value(&0), None);
+        map.insert(0..=9, 'a');
+        map.insert(10..=19, 'b');
+        map.insert(30..=39, 'c');
+        // on the boundaries
+        assert_eq!(map.get_key_value(&0), Some((&(0..=9), &'a')));
+        assert_eq!(map.get_key_value(&9), Some((&(0..=9), &'a')));
+        assert_eq!(map.get_key_value(&10), Some((&(10..=19), &'b')));
+        assert_eq!(map.get_key_value(&19), Some((&(10..=19), &'b')));
+        assert_eq!(map.get_key_value(&30), Some((&(30..=39), &'c')));
+        assert_eq!(map.get_key_value(&35), Some((&(30..=39), &'c')));
+        assert_eq!(map.get_key_value(&20), None);
+        assert_eq!(map.get_key_value(&40), None);
+        assert_eq!(map.get_key_value(&-1), None);
+    }
+
+    #[test]
+    fn test_entry() {
+        let mut map = RangeMap::new();
+        map.insert(0..=9, 'a');
+        map.insert(20..=29, 'b');
+
+        // only exactly matching ranges are occupied
+        match map.entry(0..10).unwrap() {
+            Entry::Occupied(mut entry) => {
+                assert_eq!(entry.key(), &(0..=9));
+                assert_eq!(entry.get(), &'a');
+                assert_eq!(entry.insert('c'), 'a');
+            }
+            Entry::Vacant(_) => panic!("`0..=9` is occupied"),
+        }
+        assert_eq!(map.get(&5), Some(&'c'));
+        match map.entry(0..=8).unwrap() {
+            Entry::Vacant(entry) => {
+                assert_eq!(entry.key(), &(0..=8));
+                assert_eq!(entry.overlapping().collect::<Vec<_>>(), [(&(0..=9), &'c')]);
+            }
+            Entry::Occupied(_) => panic!("`0..=8` is vacant"),
+        }
+        assert_eq!(
+            map.entry(10..=19).map(|entry| entry.key().clone()),
+            Some(10..=19)
+        );
+        let Some(Entry::Vacant(entry)) = map.entry(10..=19) else {
+            panic!("expected a vacant entry");
+        };
+        assert_eq!(entry.overlapping().count(), 0);
+
+        // a vacant insert
+        *map.entry(12..=15).unwrap().or_insert('d') = 'e';
+        assert_eq!(
+            entries(&map),
+            [(0..=9, 'c'), (12..=15, 'e'), (20..=29, 'b')]
+        );
+        assert_eq!(map.entry(12..=15).unwrap().or_insert('f'), &'e');
+        map.entry(20..=29)
+            .unwrap()
+            .and_modify(|v| *v = 'g')
+            .or_insert('h');
+        map.entry(30..=31)
+            .unwrap()
+            .and_modify(|v| *v = 'i')
+            .or_insert('j');
+        assert_eq!(
+            entries(&map),
+            [(0..=9, 'c'), (12..=15, 'e'), (20..=29, 'g'), (30..=31, 'j')]
+        );
+
+        // a vacant insert overwriting overlapping entries
+        match map.entry(5..=25).unwrap() {
+            Entry::Vacant(entry) => {
+                assert_eq!(entry.overlapping().count(), 3);
+                assert_eq!(entry.insert('k'), &'k');
+            }
+            Entry::Occupied(_) => panic!("`5..=25` is vacant"),
+        }
+        assert_eq!(
+            entries(&map),
+            [(0..=4, 'c'), (5..=25, 'k'), (26..=29, 'g'), (30..=31, 'j')]
+        );
+
+        let Some(Entry::Occupied(entry)) = map.entry(26..=29) else {
+            panic!("expected an occupied entry");
+        };
+        assert_eq!(entry.remove(), (26..=29, 'g'));
+        assert_eq!(entries(&map), [(0..=4, 'c'), (5..=25, 'k'), (30..=31, 'j')]);
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_entry_empty() {
+        let mut map = RangeMap::new();
+        map.insert(0..=9, 'a');
+        assert!(map.entry(5..5).is_none());
+        assert!(map.entry(9..=0).is_none());
+        assert!(map.entry(..i32::MIN).is_none());
+        assert_eq!(entries(&map), [(0..=9, 'a')]);
+    }
+}
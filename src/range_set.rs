@@ -0,0 +1,475 @@
+use alloc::collections::{vec_deque, VecDeque};
+#[cfg(feature = "serde")]
+use alloc::vec::Vec;
+use core::{
+    fmt::{self, Display},
+    ops::RangeInclusive,
+};
+
+use crate::{BasicNum, RangeUtil};
+
+/// A set of elements stored as disjoint ranges
+///
+/// The ranges are kept sorted by their start and coalesced, i.e. no two ranges intersect or are adjacent, e.g. inserting `0..=3` and `4..=6` results in the single range `0..=6`.
+/// They are stored in a `VecDeque`, so `pop_first` and `pop_last` are both `O(1)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeSet<T> {
+    ranges: VecDeque<RangeInclusive<T>>,
+}
+impl<T> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self {
+            ranges: VecDeque::new(),
+        }
+    }
+}
+impl<T: Ord + Clone + BasicNum> RangeSet<T> {
+    /// An empty set
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Adds all elements of `range` to the set, merging it with intersecting and adjacent ranges
+    pub fn insert(&mut self, range: impl RangeUtil<T>) {
+        let range = range.to_inclusive();
+        if range.is_empty() {
+            return;
+        }
+        // all ranges in `lo..hi` intersect or are adjacent to `range`
+        let lo = self
+            .ranges
+            .partition_point(|r| r.end() < range.start() && !r.is_adjacent(&range));
+        let hi = self
+            .ranges
+            .partition_point(|r| r.start() <= range.end() || r.is_adjacent(&range));
+        let (mut start, mut end) = range.into_inner();
+        if lo < hi {
+            start = start.min(self.ranges[lo].start().clone());
+            end = end.max(self.ranges[hi - 1].end().clone());
+        }
+        self.ranges.drain(lo..hi);
+        self.ranges.insert(lo, start..=end);
+    }
+    /// Removes all elements of `range` from the set, splitting ranges if necessary
+    pub fn remove(&mut self, range: impl RangeUtil<T>) {
+        let range = range.to_inclusive();
+        if range.is_empty() {
+            return;
+        }
+        // all ranges in `lo..hi` intersect `range`
+        let lo = self.ranges.partition_point(|r| r.end() < range.start());
+        let hi = self.ranges.partition_point(|r| r.start() <= range.end());
+        if lo == hi {
+            return;
+        }
+        // `dec`/`inc` can't overflow since there is an element before/after `range`
+        let left = (self.ranges[lo].start() < range.start())
+            .then(|| self.ranges[lo].start().clone()..=range.start().dec());
+        let right = (self.ranges[hi - 1].end() > range.end())
+            .then(|| range.end().inc()..=self.ranges[hi - 1].end().clone());
+        self.ranges.drain(lo..hi);
+        // inserting `right` first, so `left` ends up in front of it
+        if let Some(right) = right {
+            self.ranges.insert(lo, right);
+        }
+        if let Some(left) = left {
+            self.ranges.insert(lo, left);
+        }
+    }
+    /// Moves all elements at or above `at` into the returned set, like `BTreeSet::split_off`, e.g. `[0..=5, 10..=12]` split at `3` keeps `[0..=2]` and returns `[3..=5, 10..=12]`
+    pub fn split_off(&mut self, at: T) -> RangeSet<T> {
+        let i = self.ranges.partition_point(|r| r.end() < &at);
+        let mut above = self.ranges.split_off(i);
+        if let Some(straddling) = above.front_mut().filter(|r| r.start() < &at) {
+            // `straddling.start() < at`, so `dec` can't overflow
+            self.ranges.push_back(straddling.start().clone()..=at.dec());
+            *straddling = at..=straddling.end().clone();
+        }
+        Self { ranges: above }
+    }
+    /// Whether `x` is in any range of the set
+    pub fn contains(&self, x: &T) -> bool {
+        let i = self.ranges.partition_point(|r| r.end() < x);
+        self.ranges.get(i).is_some_and(|r| r.start() <= x)
+    }
+    /// The element at `index` if the elements of all ranges are laid out in order, e.g. `[0..=2, 10..=12]` at `4` is `11`
+    ///
+    /// The lengths are counted as `u128` (see `RangeUtil::cardinality`), so this doesn't overflow. `None` if `index` is past the last element.
+    pub fn nth_value(&self, mut index: u128) -> Option<T> {
+        for range in &self.ranges {
+            let start = range.start().offset_from_min();
+            // `last` instead of the length since that can overflow for `u128`/`i128`
+            let last = range.end().offset_from_min() - start;
+            if index <= last {
+                return Some(T::from_offset(start + index));
+            }
+            index -= last + 1;
+        }
+        None
+    }
+    /// Whether the set contains no elements
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+    /// Removes and returns the range with the smallest elements, `None` if the set is empty
+    pub fn pop_first(&mut self) -> Option<RangeInclusive<T>> {
+        self.ranges.pop_front()
+    }
+    /// Removes and returns the range with the largest elements, `None` if the set is empty
+    pub fn pop_last(&mut self) -> Option<RangeInclusive<T>> {
+        self.ranges.pop_back()
+    }
+    /// The disjoint ranges of the set, sorted by their start
+    pub fn iter(&self) -> vec_deque::Iter<'_, RangeInclusive<T>> {
+        self.ranges.iter()
+    }
+    /// Whether the sets have no common elements, e.g. `[0..=3, 10..=12]` and `[4..=9]` are disjoint (adjacent ranges don't overlap)
+    ///
+    /// Stops at the first intersecting pair of ranges.
+    pub fn is_disjoint(&self, other: &RangeSet<T>) -> bool {
+        let (mut a, mut b) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+        // the same walk as `intersect`
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            if x.intersects(*y) {
+                return false;
+            }
+            if x.end() < y.end() {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        true
+    }
+    /// The elements in both sets, e.g. `[0..=5, 10..=15]` and `[3..=12]` is `[3..=5, 10..=12]`
+    pub fn intersect(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let (mut a, mut b) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+        let mut intersection = VecDeque::new();
+        // both lists are sorted, so the range ending first can't intersect any later range of the other set
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            intersection.extend(x.intersection(*y));
+            if x.end() < y.end() {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        // the pieces are separated by the gaps of either set, so they are already disjoint and not adjacent
+        Self {
+            ranges: intersection,
+        }
+    }
+    /// The elements in either set, e.g. `[0..=5, 10..=15]` and `[3..=12]` is `[0..=15]`
+    pub fn union(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut union = self.clone();
+        union.extend(other.ranges.iter().cloned());
+        union
+    }
+    /// The elements in `self` but not in `other`, e.g. `[0..=5, 10..=15]` without `[3..=12]` is `[0..=2, 13..=15]`
+    ///
+    /// Each range is reduced with `RangeUtil::setminus_all`.
+    pub fn difference(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        Self {
+            ranges: self
+                .ranges
+                .iter()
+                .flat_map(|r| r.setminus_all(other.ranges.iter().cloned()))
+                .collect(),
+        }
+    }
+}
+impl<T: Ord + Clone + BasicNum, R: RangeUtil<T>> Extend<R> for RangeSet<T> {
+    fn extend<I: IntoIterator<Item = R>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+impl<T: Ord + Clone + BasicNum, R: RangeUtil<T>> FromIterator<R> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = R>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+/// Renders the ranges as `[0..=5, 10..=12]`, an empty set as `[]`
+impl<T: Display> Display for RangeSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[")?;
+        for (i, range) in self.ranges.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{}..={}", range.start(), range.end())?;
+        }
+        f.write_str("]")
+    }
+}
+/// Serialized as a list of `[start, end]` pairs
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for RangeSet<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.ranges.iter().map(|r| (r.start(), r.end())))
+    }
+}
+/// Deserialized from a list of `[start, end]` pairs, which don't have to be sorted or disjoint (they are inserted one by one)
+#[cfg(feature = "serde")]
+impl<'de, T: Ord + Clone + BasicNum + serde::Deserialize<'de>> serde::Deserialize<'de>
+    for RangeSet<T>
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let pairs = <Vec<(T, T)> as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(pairs.into_iter().map(|(start, end)| start..=end).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{ops::Bound, string::ToString, vec, vec::Vec};
+
+    use super::RangeSet;
+
+    fn ranges(set: &RangeSet<i32>) -> Vec<std::ops::RangeInclusive<i32>> {
+        set.iter().cloned().collect()
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut set = RangeSet::new();
+        set.insert(0..=3);
+        assert_eq!(ranges(&set), [0..=3]);
+        set.insert(4..=6);
+        assert_eq!(ranges(&set), [0..=6]);
+        set.insert(10..=12);
+        assert_eq!(ranges(&set), [0..=6, 10..=12]);
+        set.insert(-5..-2);
+        assert_eq!(ranges(&set), [-5..=-3, 0..=6, 10..=12]);
+        set.insert(8..=8);
+        assert_eq!(ranges(&set), [-5..=-3, 0..=6, 8..=8, 10..=12]);
+        set.insert(5..10);
+        assert_eq!(ranges(&set), [-5..=-3, 0..=12]);
+        set.insert(1..=2);
+        assert_eq!(ranges(&set), [-5..=-3, 0..=12]);
+        set.insert(-2..=-1);
+        assert_eq!(ranges(&set), [-5..=12]);
+        set.insert(20..20);
+        assert_eq!(ranges(&set), [-5..=12]);
+        set.insert(..);
+        assert_eq!(ranges(&set), [i32::MIN..=i32::MAX]);
+
+        let mut set = RangeSet::new();
+        set.insert(..0u8);
+        set.insert((Bound::Excluded(u8::MAX), Bound::Unbounded));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut set = RangeSet::new();
+        set.insert(0..=20);
+        set.remove(5..=7);
+        assert_eq!(ranges(&set), [0..=4, 8..=20]);
+        set.remove(-10..=0);
+        assert_eq!(ranges(&set), [1..=4, 8..=20]);
+        set.remove(4..=8);
+        assert_eq!(ranges(&set), [1..=3, 9..=20]);
+        set.remove(30..);
+        assert_eq!(ranges(&set), [1..=3, 9..=20]);
+        set.remove(15..);
+        assert_eq!(ranges(&set), [1..=3, 9..=14]);
+        set.remove(0..=100);
+        assert!(set.is_empty());
+
+        let mut set = RangeSet::new();
+        set.insert(..);
+        set.remove(i32::MIN..=i32::MIN);
+        set.remove(i32::MAX..=i32::MAX);
+        assert_eq!(ranges(&set), [i32::MIN + 1..=i32::MAX - 1]);
+
+        let mut set = RangeSet::new();
+        set.insert(..);
+        set.remove(..0u8);
+        set.remove((Bound::Excluded(u8::MAX), Bound::Unbounded));
+        assert_eq!(set.iter().cloned().collect::<Vec<_>>(), [0..=255]);
+    }
+
+    #[test]
+    fn test_split_off() {
+        let mut set: RangeSet<i32> = [0..=5, 10..=12].into_iter().collect();
+        let above = set.split_off(3);
+        assert_eq!(ranges(&set), [0..=2]);
+        assert_eq!(ranges(&above), [3..=5, 10..=12]);
+
+        let mut set: RangeSet<i32> = [0..=5, 10..=12].into_iter().collect();
+        let above = set.split_off(10);
+        assert_eq!(ranges(&set), [0..=5]);
+        assert_eq!(ranges(&above), [10..=12]);
+
+        let mut set: RangeSet<i32> = [0..=5, 10..=12].into_iter().collect();
+        let above = set.split_off(7);
+        assert_eq!(ranges(&set), [0..=5]);
+        assert_eq!(ranges(&above), [10..=12]);
+
+        let mut set: RangeSet<i32> = [0..=5, 10..=12].into_iter().collect();
+        let above = set.split_off(12);
+        assert_eq!(ranges(&set), [0..=5, 10..=11]);
+        assert_eq!(ranges(&above), [12..=12]);
+
+        let mut set: RangeSet<i32> = [0..=5, 10..=12].into_iter().collect();
+        assert!(set.split_off(20).is_empty());
+        assert_eq!(ranges(&set), [0..=5, 10..=12]);
+        let above = set.split_off(i32::MIN);
+        assert!(set.is_empty());
+        assert_eq!(ranges(&above), [0..=5, 10..=12]);
+    }
+
+    #[test]
+    fn test_contains() {
+        let mut set = RangeSet::new();
+        assert!(!set.contains(&0));
+        set.insert(0..=3);
+        set.insert(10..=12);
+        assert!(set.contains(&0));
+        assert!(set.contains(&3));
+        assert!(!set.contains(&4));
+        assert!(!set.contains(&9));
+        assert!(set.contains(&11));
+        assert!(!set.contains(&13));
+        assert!(!set.contains(&-1));
+    }
+
+    #[test]
+    fn test_pop_first_last() {
+        let mut set: RangeSet<i32> = [10..=12, 0..=3, 20..=20, 5..=6].into_iter().collect();
+        let mut popped = Vec::new();
+        while let Some(range) = set.pop_first() {
+            popped.push(range);
+        }
+        assert_eq!(popped, [0..=3, 5..=6, 10..=12, 20..=20]);
+        assert!(set.is_empty());
+        assert_eq!(set.pop_first(), None);
+
+        let mut set: RangeSet<i32> = [10..=12, 0..=3, 20..=20].into_iter().collect();
+        assert_eq!(set.pop_last(), Some(20..=20));
+        assert_eq!(set.pop_first(), Some(0..=3));
+        assert_eq!(set.pop_last(), Some(10..=12));
+        assert_eq!(set.pop_last(), None);
+    }
+
+    #[test]
+    fn test_nth_value() {
+        let set: RangeSet<i32> = [0..=2, 10..=12].into_iter().collect();
+        assert_eq!(set.nth_value(0), Some(0));
+        assert_eq!(set.nth_value(2), Some(2));
+        assert_eq!(set.nth_value(3), Some(10));
+        assert_eq!(set.nth_value(4), Some(11));
+        assert_eq!(set.nth_value(5), Some(12));
+        assert_eq!(set.nth_value(6), None);
+        assert_eq!(set.nth_value(u128::MAX), None);
+        assert_eq!(RangeSet::<i32>::new().nth_value(0), None);
+
+        let set: RangeSet<i8> = [-128..=-127, 100..=127].into_iter().collect();
+        assert_eq!(set.nth_value(1), Some(-127));
+        assert_eq!(set.nth_value(2), Some(100));
+        assert_eq!(set.nth_value(29), Some(127));
+        assert_eq!(set.nth_value(30), None);
+
+        let set: RangeSet<u128> = [..].into_iter().collect();
+        assert_eq!(set.nth_value(u128::MAX), Some(u128::MAX));
+    }
+
+    #[test]
+    fn test_from_iter_extend() {
+        let set: RangeSet<i32> = vec![0..=3, 10..=12, 2..=5].into_iter().collect();
+        assert_eq!(ranges(&set), [0..=5, 10..=12]);
+
+        let mut set: RangeSet<i32> = [20..=25, 7..=8, -3..=-1, 6..=6].into_iter().collect();
+        assert_eq!(ranges(&set), [-3..=-1, 6..=8, 20..=25]);
+        set.extend([0..=5, 9..=19]);
+        assert_eq!(ranges(&set), [-3..=25]);
+        set.extend(std::iter::empty::<std::ops::Range<i32>>());
+        assert_eq!(ranges(&set), [-3..=25]);
+    }
+
+    #[test]
+    fn test_is_disjoint() {
+        let a: RangeSet<i32> = [0..=3, 10..=12].into_iter().collect();
+        // touching at adjacent boundaries
+        let b: RangeSet<i32> = [4..=9].into_iter().collect();
+        assert!(a.is_disjoint(&b));
+        assert!(b.is_disjoint(&a));
+        let c: RangeSet<i32> = [-5..=-1, 5..=6, 13..=20].into_iter().collect();
+        assert!(a.is_disjoint(&c));
+        let d: RangeSet<i32> = [5..=6, 12..=13].into_iter().collect();
+        assert!(!a.is_disjoint(&d));
+        assert!(!d.is_disjoint(&a));
+        assert!(!a.is_disjoint(&a));
+        assert!(a.is_disjoint(&RangeSet::new()));
+    }
+
+    #[test]
+    fn test_intersect() {
+        let a: RangeSet<i32> = [0..=5, 10..=15].into_iter().collect();
+        let b: RangeSet<i32> = [3..=12].into_iter().collect();
+        assert_eq!(ranges(&a.intersect(&b)), [3..=5, 10..=12]);
+        assert_eq!(a.intersect(&b), b.intersect(&a));
+        let c: RangeSet<i32> = [-5..=0, 5..=10, 14..=20].into_iter().collect();
+        assert_eq!(ranges(&a.intersect(&c)), [0..=0, 5..=5, 10..=10, 14..=15]);
+        let d: RangeSet<i32> = [6..=9, 16..=20].into_iter().collect();
+        assert!(a.intersect(&d).is_empty());
+        assert_eq!(a.intersect(&a), a);
+        assert!(a.intersect(&RangeSet::new()).is_empty());
+    }
+
+    #[test]
+    fn test_union() {
+        let a: RangeSet<i32> = [0..=5, 10..=15].into_iter().collect();
+        let b: RangeSet<i32> = [3..=12].into_iter().collect();
+        assert_eq!(ranges(&a.union(&b)), [0..=15]);
+        let c: RangeSet<i32> = [6..=7, 20..=25].into_iter().collect();
+        assert_eq!(ranges(&a.union(&c)), [0..=7, 10..=15, 20..=25]);
+        assert_eq!(a.union(&c), c.union(&a));
+        assert_eq!(a.union(&RangeSet::new()), a);
+    }
+
+    #[test]
+    fn test_difference() {
+        let a: RangeSet<i32> = [0..=5, 10..=15].into_iter().collect();
+        let b: RangeSet<i32> = [3..=12].into_iter().collect();
+        assert_eq!(ranges(&a.difference(&b)), [0..=2, 13..=15]);
+        assert_eq!(ranges(&b.difference(&a)), [6..=9]);
+        let c: RangeSet<i32> = [1..=1, 4..=11, 15..=20].into_iter().collect();
+        assert_eq!(ranges(&a.difference(&c)), [0..=0, 2..=3, 12..=14]);
+        assert!(a.difference(&a).is_empty());
+        assert_eq!(a.difference(&RangeSet::new()), a);
+    }
+
+    #[test]
+    fn test_display() {
+        let mut set = RangeSet::new();
+        assert_eq!(set.to_string(), "[]");
+        set.insert(0..=5);
+        assert_eq!(set.to_string(), "[0..=5]");
+        set.insert(10..=12);
+        set.insert(-3..-2);
+        assert_eq!(set.to_string(), "[-3..=-3, 0..=5, 10..=12]");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let set: RangeSet<i32> = [0..=5, 10..=12].into_iter().collect();
+        let json = serde_json::to_string(&set).unwrap();
+        assert_eq!(json, "[[0,5],[10,12]]");
+        assert_eq!(serde_json::from_str::<RangeSet<i32>>(&json).unwrap(), set);
+
+        let set = serde_json::from_str::<RangeSet<i32>>("[[10,12],[0,3],[2,5],[6,6]]").unwrap();
+        assert_eq!(ranges(&set), [0..=6, 10..=12]);
+        assert!(serde_json::from_str::<RangeSet<i32>>("[[0,1,2]]").is_err());
+    }
+}
@@ -0,0 +1,16 @@
+/// How two ranges relate to each other, see `RangeUtil::relation`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RangeRelation {
+    /// No common elements and not adjacent, e.g. `0..=3` and `5..=9`; also if exactly one range is empty
+    Disjoint,
+    /// No common elements, but without a gap, e.g. `0..=3` and `4..=9`
+    Adjacent,
+    /// Common elements, but neither contains the other, e.g. `0..=5` and `3..=9`
+    Overlapping,
+    /// The first range contains the second, e.g. `0..=9` and `3..=5`
+    Contains,
+    /// The first range is contained in the second, e.g. `3..=5` and `0..=9`
+    ContainedBy,
+    /// Both ranges contain the same elements (see `RangeUtil::range_eq`), e.g. `0..4` and `0..=3`; also if both are empty
+    Equal,
+}
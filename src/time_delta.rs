@@ -0,0 +1,104 @@
+use chrono::TimeDelta;
+
+use crate::{BasicInt, BasicNum};
+
+/// The total number of nanoseconds, `TimeDelta::num_nanoseconds` would overflow for large durations
+fn total_nanos(delta: &TimeDelta) -> i128 {
+    delta.num_seconds() as i128 * 1_000_000_000 + delta.subsec_nanos() as i128
+}
+
+/// Steps by one nanosecond (the resolution of `TimeDelta`), so e.g. `TimeDelta::seconds(1)..TimeDelta::seconds(2)` ends at `1.999999999s`
+///
+/// `chrono::Duration` is an alias of `TimeDelta`, so this also works for `Duration` ranges.
+impl BasicNum for TimeDelta {
+    const MIN_VALUE: Self = TimeDelta::MIN;
+    const MAX_VALUE: Self = TimeDelta::MAX;
+    fn dec(&self) -> Self {
+        *self - TimeDelta::nanoseconds(1)
+    }
+    fn inc(&self) -> Self {
+        *self + TimeDelta::nanoseconds(1)
+    }
+    fn offset_from_min(&self) -> u128 {
+        (total_nanos(self) - total_nanos(&TimeDelta::MIN)) as u128
+    }
+    fn from_offset(offset: u128) -> Self {
+        let nanos = total_nanos(&TimeDelta::MIN) + offset as i128;
+        TimeDelta::new(
+            nanos.div_euclid(1_000_000_000) as i64,
+            nanos.rem_euclid(1_000_000_000) as u32,
+        )
+        .unwrap()
+    }
+}
+/// `ONE` is the step of `inc`/`dec`, i.e. one nanosecond
+impl BasicInt for TimeDelta {
+    const ZERO: Self = TimeDelta::zero();
+    const ONE: Self = TimeDelta::nanoseconds(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeDelta;
+
+    use crate::{BasicInt, BasicNum, RangeUtil};
+
+    #[test]
+    fn test_basic_num() {
+        let one = TimeDelta::nanoseconds(1);
+        assert_eq!(<TimeDelta as BasicInt>::ZERO, TimeDelta::zero());
+        assert_eq!(<TimeDelta as BasicInt>::ONE, one);
+        assert_eq!(TimeDelta::zero().inc(), one);
+        assert_eq!(TimeDelta::zero().dec(), -one);
+        assert_eq!(
+            TimeDelta::seconds(1).dec(),
+            TimeDelta::nanoseconds(999_999_999)
+        );
+        assert_eq!(TimeDelta::MAX.checked_inc(), None);
+        assert_eq!(TimeDelta::MIN.checked_dec(), None);
+        assert_eq!(TimeDelta::MIN.offset_from_min(), 0);
+        for delta in [
+            TimeDelta::MIN,
+            TimeDelta::MIN.inc(),
+            TimeDelta::seconds(-1).inc(),
+            TimeDelta::zero(),
+            TimeDelta::milliseconds(1500),
+            TimeDelta::MAX,
+        ] {
+            assert_eq!(TimeDelta::from_offset(delta.offset_from_min()), delta);
+        }
+        assert_eq!(
+            TimeDelta::MAX.offset_from_min() - TimeDelta::zero().offset_from_min(),
+            i64::MAX as u128 * 1_000_000
+        );
+    }
+
+    #[test]
+    fn test_ranges() {
+        let (hours, minutes) = (TimeDelta::hours, TimeDelta::minutes);
+        let morning = hours(8)..hours(12);
+        let meeting = hours(11)..=hours(13);
+        assert_eq!(
+            morning.intersection(&meeting),
+            Some(hours(11)..=hours(12) - TimeDelta::nanoseconds(1))
+        );
+        assert_eq!(
+            (hours(9)..=hours(10)).setminus(&(minutes(570)..)),
+            (
+                Some(hours(9)..=minutes(570) - TimeDelta::nanoseconds(1)),
+                None
+            )
+        );
+        assert!(!morning.intersects(&(hours(12)..hours(13))));
+        assert!(morning.is_adjacent(&(hours(12)..hours(13))));
+        assert_eq!(
+            (hours(1)..=hours(2)).shift(minutes(30)),
+            minutes(90)..=minutes(150)
+        );
+        assert_eq!(
+            (TimeDelta::zero()..TimeDelta::seconds(1)).cardinality(),
+            1_000_000_000
+        );
+        assert_eq!((..=TimeDelta::zero()).starts_at(), TimeDelta::MIN);
+    }
+}
@@ -0,0 +1,90 @@
+use core::ops::{Range, RangeInclusive};
+
+use crate::RangeUtil;
+
+/// `RangeUtil<usize>` extensions for ranges of slice indices, implemented for every `RangeUtil<usize>`
+pub trait UsizeRangeUtil: RangeUtil<usize> {
+    /// The half-open indices of the range inside a slice of `slice_len` elements, e.g. `(5..=15).clamp_to_slice_bounds(10) == Some(5..10)`
+    ///
+    /// The result can be used to index the slice directly, `None` if the range has no elements in `0..slice_len`.
+    fn clamp_to_slice_bounds(&self, slice_len: usize) -> Option<Range<usize>> {
+        self.intersection(&(0..slice_len))
+            // the end is below `slice_len`, so `+ 1` can't overflow
+            .map(|r| *r.start()..r.end() + 1)
+    }
+    /// Multiplies both ends of an index range by the element size `factor`, e.g. `(2..=4).scale_by(8) == 16..=32`
+    ///
+    /// Meant for converting element indices into byte offsets with `factor = size_of::<T>()`: the ends are the offsets of the first bytes of the first and last element (the last byte of the last element is `ends_at() * factor + factor - 1`). Unlike `RangeUtil::scale` this is only for `usize`, the ends saturate at `usize::MAX` (see `checked_scale_by`) and empty ranges are returned unchanged. Panics if `factor` is zero.
+    fn scale_by(&self, factor: usize) -> RangeInclusive<usize> {
+        assert!(factor > 0, "factor must be positive");
+        if self.is_empty() {
+            return self.to_inclusive();
+        }
+        self.starts_at().saturating_mul(factor)..=self.ends_at().saturating_mul(factor)
+    }
+    /// Same as `scale_by`, but `None` if a byte offset would overflow `usize`
+    fn checked_scale_by(&self, factor: usize) -> Option<RangeInclusive<usize>> {
+        assert!(factor > 0, "factor must be positive");
+        if self.is_empty() {
+            return Some(self.to_inclusive());
+        }
+        Some(self.starts_at().checked_mul(factor)?..=self.ends_at().checked_mul(factor)?)
+    }
+}
+impl<R: RangeUtil<usize>> UsizeRangeUtil for R {}
+
+#[cfg(test)]
+mod tests {
+    use super::UsizeRangeUtil;
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_clamp_to_slice_bounds() {
+        let data = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        assert_eq!((2..=5).clamp_to_slice_bounds(data.len()), Some(2..6));
+        assert_eq!((5..=15).clamp_to_slice_bounds(data.len()), Some(5..10));
+        assert_eq!((..).clamp_to_slice_bounds(data.len()), Some(0..10));
+        assert_eq!((9..).clamp_to_slice_bounds(data.len()), Some(9..10));
+        assert_eq!((10..=15).clamp_to_slice_bounds(data.len()), None);
+        assert_eq!((5..=4).clamp_to_slice_bounds(data.len()), None);
+        assert_eq!((..).clamp_to_slice_bounds(0), None);
+        let range = (7..=100).clamp_to_slice_bounds(data.len()).unwrap();
+        assert_eq!(data[range], [7, 8, 9]);
+    }
+
+    #[test]
+    fn test_scale_by() {
+        let data = [0u32; 10];
+        let size = core::mem::size_of::<u32>();
+        assert_eq!((2..=4).scale_by(size), 8..=16);
+        assert_eq!((2..=4).scale_by(8), 16..=32);
+        assert_eq!((2..5).scale_by(8), 16..=32);
+        assert_eq!((3..=3).scale_by(1), 3..=3);
+        assert_eq!((..).scale_by(2), 0..=usize::MAX);
+        assert_eq!((usize::MAX / 2..).scale_by(4), usize::MAX..=usize::MAX);
+        assert!((5..5).scale_by(8).is_empty());
+        let bytes = (0..data.len()).scale_by(size);
+        assert_eq!(
+            (*bytes.start()..=bytes.end() + size - 1).count(),
+            core::mem::size_of_val(&data)
+        );
+    }
+
+    #[test]
+    fn test_checked_scale_by() {
+        assert_eq!((2..=4).checked_scale_by(8), Some(16..=32));
+        assert_eq!(
+            (..=usize::MAX / 8).checked_scale_by(8),
+            Some(0..=usize::MAX / 8 * 8)
+        );
+        assert_eq!((..=usize::MAX / 8 + 1).checked_scale_by(8), None);
+        assert_eq!((usize::MAX / 2 + 1..).checked_scale_by(2), None);
+        assert!((5..5).checked_scale_by(8).is_some_and(|r| r.is_empty()));
+    }
+
+    #[test]
+    #[should_panic(expected = "factor must be positive")]
+    fn test_scale_by_zero() {
+        (0..=10).scale_by(0);
+    }
+}
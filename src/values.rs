@@ -0,0 +1,149 @@
+use core::{
+    iter::FusedIterator,
+    ops::{Add, Bound, RangeBounds, Sub},
+};
+
+use crate::{BasicInt, BasicNum};
+
+/// Iterator over the elements of a range, see `RangeUtil::values`
+#[derive(Debug, Clone)]
+pub struct RangeValues<T> {
+    /// The remaining elements `front..=back`, `None` if exhausted
+    remaining: Option<(T, T)>,
+}
+impl<T: Ord + Clone + BasicNum> RangeValues<T> {
+    pub(crate) fn new(start: T, end: T) -> Self {
+        Self {
+            remaining: (start <= end).then_some((start, end)),
+        }
+    }
+}
+impl<T: Ord + Clone + BasicNum> Iterator for RangeValues<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let (front, back) = self.remaining.take()?;
+        // not incrementing the last element, so this can't overflow at `MAX_VALUE`
+        if front < back {
+            self.remaining = Some((front.inc(), back));
+        }
+        Some(front)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let Some((front, back)) = &self.remaining else {
+            return (0, Some(0));
+        };
+        // the count may not fit into `usize` (e.g. for `u128`), then there is no upper bound
+        match (back.offset_from_min() - front.offset_from_min())
+            .checked_add(1)
+            .and_then(|count| usize::try_from(count).ok())
+        {
+            Some(count) => (count, Some(count)),
+            None => (usize::MAX, None),
+        }
+    }
+}
+impl<T: Ord + Clone + BasicNum> DoubleEndedIterator for RangeValues<T> {
+    fn next_back(&mut self) -> Option<T> {
+        let (front, back) = self.remaining.take()?;
+        // not decrementing the first element, so this can't overflow at `MIN_VALUE`
+        if front < back {
+            self.remaining = Some((front, back.dec()));
+        }
+        Some(back)
+    }
+}
+impl<T: Ord + Clone + BasicNum> FusedIterator for RangeValues<T> {}
+/// Like for `RangeInclusive`, only for types whose number of elements always fits into `usize`
+macro_rules! impl_exact_size {
+    ($($t:ty),*) => {
+        $(impl ExactSizeIterator for RangeValues<$t> {})*
+    };
+}
+impl_exact_size!(u8, i8, u16, i16);
+#[cfg(target_pointer_width = "64")]
+impl_exact_size!(u32, i32);
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl_exact_size!(char);
+
+/// A resolved range `start..=end` that can always be iterated, see `RangeUtil::finite`
+///
+/// Unlike `RangeFrom` or `RangeFull`, this implements `IntoIterator` (with `RangeValues`), also for ranges ending at `T::MAX_VALUE`. It is empty if `start > end`; all empty ranges are stored as `T::MAX_VALUE..=T::MIN_VALUE`, so they compare and hash equal, e.g. `(5..5).finite() == (3..=0).finite()`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FiniteRange<T> {
+    start: T,
+    end: T,
+}
+impl<T: Ord + Clone + BasicNum> FiniteRange<T> {
+    pub(crate) fn new(start: T, end: T) -> Self {
+        if start > end {
+            return Self {
+                start: T::MAX_VALUE,
+                end: T::MIN_VALUE,
+            };
+        }
+        Self { start, end }
+    }
+}
+impl<T> FiniteRange<T> {
+    /// The first element (if not empty)
+    pub fn start(&self) -> &T {
+        &self.start
+    }
+    /// The last element (if not empty)
+    pub fn end(&self) -> &T {
+        &self.end
+    }
+}
+impl<T> RangeBounds<T> for FiniteRange<T> {
+    fn start_bound(&self) -> Bound<&T> {
+        Bound::Included(&self.start)
+    }
+    fn end_bound(&self) -> Bound<&T> {
+        Bound::Included(&self.end)
+    }
+}
+impl<T: Ord + Clone + BasicNum> IntoIterator for FiniteRange<T> {
+    type Item = T;
+    type IntoIter = RangeValues<T>;
+
+    fn into_iter(self) -> RangeValues<T> {
+        RangeValues::new(self.start, self.end)
+    }
+}
+
+/// Iterator over the elements of a range with a fixed step, see `RangeUtil::step_values`
+#[derive(Debug, Clone)]
+pub struct StepValues<T> {
+    /// The next element and the (inclusive) end, `None` if exhausted
+    remaining: Option<(T, T)>,
+    step: T,
+}
+impl<T: Ord + Clone + BasicInt + Add<Output = T> + Sub<Output = T>> StepValues<T> {
+    pub(crate) fn new(start: T, end: T, step: T) -> Self {
+        assert!(step > T::ZERO, "step must be positive");
+        Self {
+            remaining: (start <= end).then_some((start, end)),
+            step,
+        }
+    }
+}
+impl<T: Ord + Clone + BasicNum + Add<Output = T> + Sub<Output = T>> Iterator for StepValues<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let (current, end) = self.remaining.take()?;
+        // `MAX_VALUE - step` can't overflow since `step` is positive
+        if current <= T::MAX_VALUE - self.step.clone() {
+            let next = current.clone() + self.step.clone();
+            if next <= end {
+                self.remaining = Some((next, end));
+            }
+        }
+        Some(current)
+    }
+}
+impl<T: Ord + Clone + BasicNum + Add<Output = T> + Sub<Output = T>> FusedIterator
+    for StepValues<T>
+{
+}
@@ -0,0 +1,145 @@
+use core::{iter::Chain, ops::RangeInclusive};
+
+use crate::{BasicInt, RangeUtil, RangeValues};
+
+/// A range on a circular domain `0..modulus`, wrapping around if `start > end`, e.g. on a clock of size 12 `10..=2` contains `10, 11, 0, 1, 2`
+///
+/// The range always contains at least one element (`start == end`) and is the whole domain if `start` follows `end`, e.g. `3..=2`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WrappingRange<T> {
+    start: T,
+    end: T,
+    modulus: T,
+}
+impl<T: Ord + Clone + BasicInt> WrappingRange<T> {
+    /// The elements from `start` to `end` (inclusive) in `0..modulus`
+    ///
+    /// Panics if `modulus` isn't positive or `start`/`end` are outside of `0..modulus`.
+    pub fn new(start: T, end: T, modulus: T) -> Self {
+        assert!(modulus > T::ZERO, "modulus must be positive");
+        assert!(
+            (T::ZERO..modulus.clone()).includes(&start)
+                && (T::ZERO..modulus.clone()).includes(&end),
+            "start and end must be in `0..modulus`"
+        );
+        Self {
+            start,
+            end,
+            modulus,
+        }
+    }
+    /// The first element
+    pub fn start(&self) -> &T {
+        &self.start
+    }
+    /// The last element
+    pub fn end(&self) -> &T {
+        &self.end
+    }
+    /// The size of the domain `0..modulus`
+    pub fn modulus(&self) -> &T {
+        &self.modulus
+    }
+    /// Whether the range crosses `modulus - 1` to `0`, i.e. `start > end`
+    pub fn is_wrapping(&self) -> bool {
+        self.start > self.end
+    }
+    /// The linear ranges covering the same elements, `start..=modulus - 1` and `0..=end` if wrapping, otherwise just `start..=end`
+    pub fn parts(&self) -> (RangeInclusive<T>, Option<RangeInclusive<T>>) {
+        if self.is_wrapping() {
+            (
+                self.start.clone()..=self.modulus.dec(),
+                Some(T::ZERO..=self.end.clone()),
+            )
+        } else {
+            (self.start.clone()..=self.end.clone(), None)
+        }
+    }
+    /// Whether `x` is in the range, `false` if `x` is outside of `0..modulus`
+    pub fn includes(&self, x: &T) -> bool {
+        let (first, second) = self.parts();
+        first.includes(x) || second.is_some_and(|second| second.includes(x))
+    }
+    /// Whether both ranges have at least one element in common, the moduli should be equal
+    pub fn intersects(&self, other: &Self) -> bool {
+        let (first, second) = self.parts();
+        let (other_first, other_second) = other.parts();
+        [Some(first), second].iter().flatten().any(|part| {
+            [Some(other_first.clone()), other_second.clone()]
+                .iter()
+                .flatten()
+                .any(|other_part| part.intersects(other_part))
+        })
+    }
+    /// Iterates over all elements starting at `start`, wrapping around to `0` after `modulus - 1`
+    pub fn values(&self) -> Chain<RangeValues<T>, RangeValues<T>> {
+        let (first, second) = self.parts();
+        // an empty range if not wrapping
+        let second = second.unwrap_or(T::ONE..=T::ZERO);
+        first.values().chain(second.values())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec::Vec;
+
+    use super::WrappingRange;
+
+    #[test]
+    fn test_includes() {
+        let clock = WrappingRange::new(10, 2, 12);
+        assert!(clock.is_wrapping());
+        assert_eq!(clock.parts(), (10..=11, Some(0..=2)));
+        for x in [10, 11, 0, 1, 2] {
+            assert!(clock.includes(&x));
+        }
+        for x in [3, 5, 9, 12, -1] {
+            assert!(!clock.includes(&x));
+        }
+
+        let linear = WrappingRange::new(3, 7, 12);
+        assert!(!linear.is_wrapping());
+        assert_eq!(linear.parts(), (3..=7, None));
+        assert!(linear.includes(&3) && linear.includes(&7));
+        assert!(!linear.includes(&2) && !linear.includes(&8));
+
+        let full = WrappingRange::new(3, 2, 12);
+        assert!((0..12).all(|x| full.includes(&x)));
+        let single = WrappingRange::new(5u8, 5, 6);
+        assert!(single.includes(&5) && !single.includes(&4));
+    }
+
+    #[test]
+    fn test_intersects() {
+        let clock = |start, end| WrappingRange::new(start, end, 12);
+        assert!(clock(10, 2).intersects(&clock(1, 4)));
+        assert!(clock(10, 2).intersects(&clock(11, 0)));
+        assert!(clock(10, 2).intersects(&clock(9, 10)));
+        assert!(clock(10, 2).intersects(&clock(8, 1)));
+        assert!(!clock(10, 2).intersects(&clock(3, 9)));
+        assert!(!clock(3, 9).intersects(&clock(10, 2)));
+        assert!(clock(3, 9).intersects(&clock(9, 3)));
+        assert!(!clock(3, 5).intersects(&clock(6, 8)));
+    }
+
+    #[test]
+    fn test_values() {
+        assert_eq!(
+            WrappingRange::new(10, 2, 12).values().collect::<Vec<_>>(),
+            [10, 11, 0, 1, 2]
+        );
+        assert_eq!(
+            WrappingRange::new(3, 5, 12).values().collect::<Vec<_>>(),
+            [3, 4, 5]
+        );
+        assert_eq!(WrappingRange::new(3, 2, 12).values().count(), 12);
+        assert_eq!(WrappingRange::new(250u8, 3, 255).values().count(), 9);
+    }
+
+    #[test]
+    #[should_panic(expected = "start and end must be in `0..modulus`")]
+    fn test_out_of_domain() {
+        WrappingRange::new(10, 12, 12);
+    }
+}